@@ -0,0 +1,20 @@
+//! Fuzz the parser with arbitrary input, then each conversion of the result.
+//! Run with `cargo +nightly fuzz run parse` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scholarly_identifiers::identifiers::Identifier;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    let identifier = Identifier::parse(&input);
+    identifier.to_uri();
+    identifier.normalise();
+
+    let (stable_string, type_id) = identifier.to_id_string_pair();
+    Identifier::from_id_string_pair(&stable_string, type_id);
+
+    Identifier::extract_all(&input);
+});
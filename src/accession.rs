@@ -0,0 +1,199 @@
+//! INSDC sequence accession numbers, as used by GenBank, ENA and DDBJ, and NCBI RefSeq
+//! See <https://www.ncbi.nlm.nih.gov/genbank/acc_prefix/> and <https://www.ncbi.nlm.nih.gov/books/NBK21091/table/ch18.T.refseq_accession_numbers_and_mole/>
+//!
+//! Accessions identify biological sequences. The classic nucleotide forms are one letter and five digits, e.g.
+//! "U00096", or two letters and six or eight digits, e.g. "AB123456". RefSeq accessions have a two letter prefix and an
+//! underscore, e.g. "NM_001301717". Either may have a version suffix, e.g. "AB123456.1".
+//!
+//! Accessions are short and look like many other codes, so only the strict grammar is accepted, as the whole string,
+//! in upper case. They're represented as written, including any version.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts of NCBI.
+const HOSTS: &[&str] = &["www.ncbi.nlm.nih.gov", "ncbi.nlm.nih.gov"];
+
+/// Paths of the NCBI sequence databases, which precede the accession.
+const DATABASE_PATHS: &[&str] = &["nuccore/", "protein/"];
+
+/// RefSeq prefixes for protein sequences. All other prefixes are nucleotide sequences.
+const PROTEIN_PREFIXES: &[&str] = &["AP_", "NP_", "WP_", "XP_", "YP_"];
+
+lazy_static! {
+    /// Classic INSDC nucleotide or RefSeq accession, with an optional version.
+    static ref ACCESSION_RE: Regex = Regex::new(
+        r"^(?:[A-Z]\d{5}|[A-Z]{2}\d{6}|[A-Z]{2}\d{8}|(?:AC|AP|NC|NG|NM|NP|NR|NT|NW|WP|XM|XP|XR|YP)_(?:\d{6}|\d{9}))(?:\.\d+)?$"
+    )
+    .unwrap();
+}
+
+/// Parse an input string as an accession number.
+///
+/// Accepts:
+///  - Classic and RefSeq accessions, with or without a version, e.g. "U00096", "AB123456.1" or "NM_001301717".
+///  - NCBI nucleotide or protein URL, e.g. "https://www.ncbi.nlm.nih.gov/nuccore/NM_001301717".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if ACCESSION_RE.is_match(&input.raw) {
+        Some(Identifier::Accession(String::from(input.raw.as_ref())))
+    } else if HOSTS.contains(&input.host_lowercase()?.as_str()) {
+        let path = input.path_no_slash()?;
+
+        DATABASE_PATHS
+            .iter()
+            .find_map(|database| path.strip_prefix(database))
+            .filter(|accession| ACCESSION_RE.is_match(accession))
+            .map(|accession| Identifier::Accession(String::from(accession)))
+    } else {
+        None
+    }
+}
+
+/// Encode an accession as a URI on NCBI, under the protein or nucleotide database as appropriate.
+/// Will always return a result if an accession type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Accession(value) => {
+            let database = if PROTEIN_PREFIXES
+                .iter()
+                .any(|prefix| value.starts_with(prefix))
+            {
+                "protein"
+            } else {
+                "nuccore"
+            };
+
+            Some(format!(
+                "https://www.ncbi.nlm.nih.gov/{}/{}",
+                database, value
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Encode an accession as a stable string, as written.
+/// Will always return a String if an accession type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Accession(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod accession_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn classic() {
+        let examples = ["U00096", "AB123456", "AB12345678", "AB123456.1", "U00096.3"];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::Accession(String::from(*example)),
+                Identifier::parse(example),
+                "Expected {} to be an accession",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn refseq() {
+        let examples = ["NM_001301717", "NC_000913.3", "NP_000509.1", "XM_123456"];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::Accession(String::from(*example)),
+                Identifier::parse(example),
+                "Expected {} to be an accession",
+                example
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn ncbi() {
+        let expected = Identifier::Accession(String::from("NM_001301717"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.ncbi.nlm.nih.gov/nuccore/NM_001301717")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://ncbi.nlm.nih.gov/nuccore/NM_001301717")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "https://www.ncbi.nlm.nih.gov/gene/NM_001301717"
+            )),
+            Identifier::parse("https://www.ncbi.nlm.nih.gov/gene/NM_001301717"),
+            "Only sequence databases are recognised."
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Wrong number of digits.
+            "U0009",
+            "AB1234567",
+            "NM_0013017",
+            // Unknown RefSeq prefix.
+            "QQ_001301717",
+            // Lower case.
+            "nm_001301717",
+            // Not the whole string.
+            "see NM_001301717",
+            "NM_001301717.",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Accession,
+                "Expected {} not to be an accession",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod accession_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://www.ncbi.nlm.nih.gov/nuccore/NM_001301717"
+            )),
+            Identifier::parse("NM_001301717").to_uri()
+        );
+
+        assert_eq!(
+            Some(String::from(
+                "https://www.ncbi.nlm.nih.gov/nuccore/AB123456.1"
+            )),
+            Identifier::parse("AB123456.1").to_uri()
+        );
+
+        assert_eq!(
+            Some(String::from(
+                "https://www.ncbi.nlm.nih.gov/protein/NP_000509.1"
+            )),
+            Identifier::parse("NP_000509.1").to_uri()
+        );
+    }
+}
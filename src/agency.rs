@@ -0,0 +1,123 @@
+//! DOI Registration Agencies
+//! See <https://www.doi.org/the-community/existing-registration-agencies/>
+//!
+//! Every DOI prefix is allocated to one Registration Agency, such as Crossref or DataCite, but the prefix doesn't say
+//! which. The authoritative answer comes from the Handle system, e.g. "https://doi.org/ra/10.5555". As that needs a
+//! network request, this is a bundled table of well-known prefixes instead. It covers a lot of DOIs, but only a small
+//! fraction of prefixes, so a prefix that isn't in the table has an unknown agency, not no agency.
+//!
+//! The table is a hand-picked snapshot of large prefixes, compiled in October 2026. It goes stale as prefixes are
+//! allocated, and when a prefix moves to another agency, which does happen. Refresh it by looking up each prefix with
+//! the doi.org RA lookup API, e.g. "https://doi.org/ra/10.1002".
+
+use crate::identifiers::Identifier;
+
+/// A DOI Registration Agency.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Agency {
+    /// Crossref, mostly for scholarly publications.
+    /// See <https://www.crossref.org>
+    Crossref,
+
+    /// DataCite, mostly for research data and software.
+    /// See <https://datacite.org>
+    DataCite,
+}
+
+/// Well-known prefixes and their agencies, as of October 2026.
+const PREFIXES: &[(&str, Agency)] = &[
+    // Wiley.
+    ("10.1002", Agency::Crossref),
+    // Springer.
+    ("10.1007", Agency::Crossref),
+    // Elsevier.
+    ("10.1016", Agency::Crossref),
+    // Nature.
+    ("10.1038", Agency::Crossref),
+    // Taylor & Francis.
+    ("10.1080", Agency::Crossref),
+    // Oxford University Press.
+    ("10.1093", Agency::Crossref),
+    // American Physical Society.
+    ("10.1103", Agency::Crossref),
+    // IEEE.
+    ("10.1109", Agency::Crossref),
+    // Science.
+    ("10.1126", Agency::Crossref),
+    // ACM.
+    ("10.1145", Agency::Crossref),
+    // SAGE.
+    ("10.1177", Agency::Crossref),
+    // PLOS.
+    ("10.1371", Agency::Crossref),
+    // Crossref Funder Registry.
+    ("10.13039", Agency::Crossref),
+    // DataCite test prefix.
+    ("10.5072", Agency::DataCite),
+    // Dryad.
+    ("10.5061", Agency::DataCite),
+    // Zenodo.
+    ("10.5281", Agency::DataCite),
+    // Crossref test prefix.
+    ("10.5555", Agency::Crossref),
+    // Figshare.
+    ("10.6084", Agency::DataCite),
+    // OSF.
+    ("10.17605", Agency::DataCite),
+    // arXiv.
+    ("10.48550", Agency::DataCite),
+    // IGSN.
+    ("10.58052", Agency::DataCite),
+];
+
+/// Look up the Registration Agency of a DOI's prefix in the bundled table.
+/// Returns None if the identifier isn't a DOI or the prefix isn't in the table.
+pub(crate) fn registration_agency(input: &Identifier) -> Option<Agency> {
+    match input {
+        Identifier::Doi { prefix, suffix: _ } => PREFIXES
+            .iter()
+            .find(|(candidate, _)| candidate == prefix)
+            .map(|(_, agency)| *agency),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod agency_tests {
+    use super::*;
+
+    #[test]
+    fn known() {
+        assert_eq!(
+            Some(Agency::Crossref),
+            Identifier::parse("https://doi.org/10.1371/journal.pone.0190046")
+                .doi_registration_agency()
+        );
+
+        assert_eq!(
+            Some(Agency::DataCite),
+            Identifier::parse("10.5281/zenodo.1234567").doi_registration_agency()
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            None,
+            Identifier::parse("10.99999/12345678").doi_registration_agency(),
+            "Prefix isn't in the table."
+        );
+
+        assert_eq!(
+            None,
+            Identifier::parse("10.5281.1/12345678").doi_registration_agency(),
+            "Only the whole prefix is matched."
+        );
+
+        assert_eq!(
+            None,
+            Identifier::parse("https://orcid.org/0000-0002-1694-233X").doi_registration_agency(),
+            "Not a DOI."
+        );
+    }
+}
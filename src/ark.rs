@@ -0,0 +1,149 @@
+//! ARK, Archival Resource Key
+//! See <https://arks.org>
+//!
+//! Used by libraries, archives and museums. An ARK is made of a NAAN (Name Assigning Authority Number) and a name,
+//! e.g. "ark:/12345/abcdef". It may be expressed on the host of any NMA (Name Mapping Authority), e.g.
+//! "https://n2t.net/ark:/12345/abcdef".
+//!
+//! ARKs are case-sensitive, so the case of the name is preserved.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Match an ARK, with optional slash after the label.
+    /// Group 1 is the NAAN, group 2 is the name.
+    static ref ARK_RE: Regex = Regex::new(r"^(?i:ark):/?(\d+)/(.+)$").unwrap();
+}
+
+/// Parse an input string as an ARK.
+///
+/// Accepts:
+///  - Bare ARK, e.g. "ark:/12345/abcdef" or "ark:12345/abcdef".
+///  - ARK on an NMA host, e.g. "https://n2t.net/ark:/12345/abcdef".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if let Some(result) = construct(&input.raw) {
+        Some(result)
+    } else if input.host().is_some() {
+        input.path_no_slash().and_then(|path| construct(&path))
+    } else {
+        None
+    }
+}
+
+fn construct(input: &str) -> Option<Identifier> {
+    let groups = ARK_RE.captures(input)?;
+
+    Some(Identifier::Ark {
+        naan: String::from(groups.get(1)?.as_str()),
+        name: String::from(groups.get(2)?.as_str()),
+    })
+}
+
+/// Encode an ARK as a URI on the N2T resolver.
+/// Will always return a result if an ARK type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Ark { naan, name } => Some(format!("https://n2t.net/ark:/{}/{}", naan, name)),
+        _ => None,
+    }
+}
+
+/// Encode an ARK as a stable string, without a resolver.
+/// Will always return a String if an ARK type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Ark { naan, name } => Some(format!("ark:/{}/{}", naan, name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod ark_parser_tests {
+    use super::*;
+
+    #[test]
+    fn scheme() {
+        let expected = Identifier::Ark {
+            naan: String::from("12345"),
+            name: String::from("x6np1wh8k"),
+        };
+
+        assert_eq!(expected, Identifier::parse("ark:/12345/x6np1wh8k"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("ark:12345/x6np1wh8k"),
+            "Slash after the label is optional."
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("ARK:/12345/x6np1wh8k"),
+            "Label is case-insensitive."
+        );
+    }
+
+    #[test]
+    fn resolver() {
+        let expected = Identifier::Ark {
+            naan: String::from("13030"),
+            name: String::from("tf5p30086k"),
+        };
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://n2t.net/ark:/13030/tf5p30086k")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://ark.cdlib.org/ark:/13030/tf5p30086k"),
+            "Any NMA host should be recognised."
+        );
+    }
+
+    #[test]
+    fn case_sensitive() {
+        assert_eq!(
+            Identifier::Ark {
+                naan: String::from("12345"),
+                name: String::from("AbCdEf"),
+            },
+            Identifier::parse("ark:/12345/AbCdEf"),
+            "Name should preserve case."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com/ark/12345/abcdef")),
+            Identifier::parse("https://example.com/ark/12345/abcdef")
+        );
+
+        assert_eq!(
+            Identifier::String(String::from("ark:/12345")),
+            Identifier::parse("ark:/12345"),
+            "No name."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod ark_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://n2t.net/ark:/12345/x6np1wh8k")),
+            Identifier::parse("ark:12345/x6np1wh8k").to_uri()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Compact identifiers, as resolved by identifiers.org and Bioregistry
+//! See <https://identifiers.org> and <https://bioregistry.io>
+//!
+//! A compact identifier is a prefix naming the type and a local identifier, e.g. "orcid:0000-0002-1694-233X", and
+//! can be wrapped in an identifiers.org URL, e.g. "https://identifiers.org/doi:10.5555/12345678". Compact identifiers
+//! aren't a type of their own. They're unwrapped and the local identifier is parsed by the parser for the type, via the
+//! type's own resolver URL.
+//!
+//! Only prefixes for types in this crate are unwrapped, so e.g. "pubmed:" isn't recognised, as there's no PubMed type.
+
+use crate::identifiers::IdentifierParseInput;
+use alloc::format;
+use alloc::string::String;
+
+/// Hosts of identifiers.org.
+const HOSTS: &[&str] = &["identifiers.org", "www.identifiers.org"];
+
+/// If the input is a compact identifier with the given prefix, return the local identifier under the given resolver,
+/// e.g. "https://identifiers.org/orcid:0000-0002-1694-233X" with the prefix "orcid" and resolver "https://orcid.org/"
+/// gives "https://orcid.org/0000-0002-1694-233X".
+///
+/// Accepts an identifiers.org URL, or the bare compact identifier.
+pub(crate) fn unwrap(input: &IdentifierParseInput, prefix: &str, resolver: &str) -> Option<String> {
+    let compact = if input.raw.contains("://") {
+        let host = input.host_lowercase()?;
+
+        if HOSTS.contains(&host.as_str()) {
+            input.path_no_slash()?
+        } else {
+            return None;
+        }
+    } else {
+        String::from(input.raw.as_ref())
+    };
+
+    // Prefixes are case-insensitive, but the local identifier's case is kept for its own parser.
+    let (candidate, local) = compact.split_once(':')?;
+
+    if candidate.eq_ignore_ascii_case(prefix) && !local.is_empty() {
+        Some(format!("{}{}", resolver, local))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod compact_tests {
+    use crate::identifiers::{Identifier, IdentifierType};
+    use alloc::string::String;
+
+    #[test]
+    fn identifiers_org() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12345678")
+            },
+            Identifier::parse("https://identifiers.org/doi:10.5555/12345678")
+        );
+
+        assert_eq!(
+            Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            Identifier::parse("https://identifiers.org/orcid:0000-0002-1694-233X")
+        );
+
+        assert_eq!(
+            Identifier::Ror(String::from("02twcfp32")),
+            Identifier::parse("http://identifiers.org/ROR:02twcfp32"),
+            "Prefix should be case-insensitive."
+        );
+    }
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            Identifier::parse("orcid:0000-0002-1694-233X")
+        );
+
+        assert_eq!(
+            Identifier::Ror(String::from("02twcfp32")),
+            Identifier::parse("ror:02twcfp32")
+        );
+    }
+
+    #[test]
+    fn not_compact() {
+        let examples = [
+            // Invalid local identifier.
+            "https://identifiers.org/orcid:0000-0002-1694-2330",
+            // Unknown prefix.
+            "https://identifiers.org/pubmed:12345678",
+            // Prefix on another host.
+            "https://example.com/orcid:0000-0002-1694-233X",
+        ];
+
+        for example in examples.iter() {
+            let identifier_type = Identifier::parse(example).identifier_type();
+
+            assert!(
+                !matches!(
+                    identifier_type,
+                    IdentifierType::Doi | IdentifierType::Orcid | IdentifierType::Ror
+                ),
+                "Expected {} not to be unwrapped",
+                example
+            );
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Diagnostics
+//!
+//! Explain how an input was parsed, and if it wasn't recognised, whether it was nearly a known type.
+
+use crate::identifiers::{Identifier, IdentifierParseInput, IdentifierType, PARSERS};
+use crate::{doi, isbn, orcid, ror};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// If the input looks like the given type but wasn't recognised as it, explain why.
+/// Only some types can detect near misses.
+fn near_miss(
+    identifier_type: IdentifierType,
+    input: &IdentifierParseInput,
+) -> Option<&'static str> {
+    match identifier_type {
+        IdentifierType::Doi => doi::near_miss(input),
+        IdentifierType::Orcid => orcid::near_miss(input),
+        IdentifierType::Isbn => isbn::near_miss(input),
+        IdentifierType::Ror => ror::near_miss(input),
+        _ => None,
+    }
+}
+
+/// A short reason why the given type's parser rejected an input that wasn't a near miss.
+fn rejection_reason(identifier_type: IdentifierType) -> &'static str {
+    match identifier_type {
+        IdentifierType::Doi => "no 10. prefix",
+        IdentifierType::Orcid => "not an ORCID iD on orcid.org",
+        IdentifierType::Isbn => "not 10 or 13 ISBN digits",
+        IdentifierType::Ror => "not a ROR ID on ror.org",
+        IdentifierType::Ark => "no ark: label",
+        IdentifierType::Ismn => "not a 979-0 or M-prefixed ISMN",
+        IdentifierType::ResearcherId => "not a ResearcherID",
+        IdentifierType::Swhid => "not a swh:1: SWHID",
+        IdentifierType::Grid => "not a grid. GRID ID",
+        IdentifierType::Oclc => "not a prefixed OCLC number",
+        IdentifierType::Gnd => "not a GND ID on d-nb.info or with (DE-588)",
+        IdentifierType::Dimensions => "not a pub. Dimensions ID",
+        IdentifierType::SemanticScholar => "not a Semantic Scholar Corpus ID or paper ID",
+        IdentifierType::Purl => "not a URL on a PURL host",
+        IdentifierType::Isrc => "not 12 ISRC characters",
+        IdentifierType::Istc => "not 16 ISTC characters with a check character",
+        IdentifierType::Accession => "not a strict INSDC or RefSeq accession",
+        IdentifierType::ShortDoi => "not a shortDOI under the \"10\" prefix",
+        IdentifierType::Iswc => "not an ISWC with a valid check digit",
+        IdentifierType::Pdb => "not a classic or extended PDB ID",
+        IdentifierType::UrnNbn => "not a URN:NBN with a country code",
+        IdentifierType::Uri => "not a valid URI",
+        IdentifierType::Custom => "not recognised by a custom parser",
+        IdentifierType::String => "not recognised",
+    }
+}
+
+/// A record of the parsers tried for an input, in order of precedence.
+#[derive(Debug, PartialEq)]
+pub struct ParseTrace {
+    /// Each parser tried, up to and including the one that recognised the input, if any.
+    pub attempts: Vec<ParseAttempt>,
+}
+
+/// A single parser tried for an input.
+#[derive(Debug, PartialEq)]
+pub struct ParseAttempt {
+    /// The type that the parser recognises.
+    pub identifier_type: IdentifierType,
+
+    /// Why the parser rejected the input, or None if it recognised it.
+    pub rejection: Option<&'static str>,
+}
+
+impl ParseTrace {
+    /// The reason the given type's parser rejected the input, if it was tried and did reject it.
+    pub fn rejection(&self, identifier_type: IdentifierType) -> Option<&'static str> {
+        self.attempts
+            .iter()
+            .find(|attempt| attempt.identifier_type == identifier_type)
+            .and_then(|attempt| attempt.rejection)
+    }
+}
+
+pub(crate) fn parse_with_trace(input: &str) -> (Identifier, ParseTrace) {
+    let parse_input = IdentifierParseInput::build(input);
+    let mut attempts = Vec::new();
+
+    for parser in PARSERS.iter() {
+        let identifier_type = parser.identifier_type();
+
+        if let Some(result) = parser.try_parse(&parse_input) {
+            attempts.push(ParseAttempt {
+                identifier_type,
+                rejection: None,
+            });
+
+            return (result, ParseTrace { attempts });
+        }
+
+        attempts.push(ParseAttempt {
+            identifier_type,
+            rejection: Some(
+                near_miss(identifier_type, &parse_input)
+                    .unwrap_or_else(|| rejection_reason(identifier_type)),
+            ),
+        });
+    }
+
+    // Fall-back case.
+    (
+        Identifier::String(parse_input.raw.into_owned()),
+        ParseTrace { attempts },
+    )
+}
+
+/// Structured diagnostics for a parsed input.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Diagnosis {
+    /// The type the input was parsed as.
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub identifier_type: IdentifierType,
+
+    /// The stable numeric type id of the parsed type.
+    pub type_id: u32,
+
+    /// The URI of the parsed identifier, if it has one.
+    pub uri: Option<String>,
+
+    /// If the input fell through to a URI or String, the type it nearly was.
+    pub near_miss_type: Option<IdentifierType>,
+
+    /// If the input fell through to a URI or String, why it wasn't the near-miss type.
+    pub reason: Option<&'static str>,
+
+    /// If the input was recognised, but may not be what was meant, why.
+    pub warning: Option<&'static str>,
+}
+
+pub(crate) fn diagnose(input: &str) -> Diagnosis {
+    let identifier = Identifier::parse(input);
+    let identifier_type = identifier.identifier_type();
+
+    // Only look for near misses when the input wasn't recognised as a specific type.
+    let near_miss = match identifier_type {
+        IdentifierType::Uri | IdentifierType::String => {
+            let parse_input = IdentifierParseInput::build(input);
+
+            PARSERS.iter().find_map(|parser| {
+                let near_miss_type = parser.identifier_type();
+                near_miss(near_miss_type, &parse_input).map(|reason| (near_miss_type, reason))
+            })
+        }
+        _ => None,
+    };
+
+    let warning = match identifier_type {
+        IdentifierType::Doi => doi::warning(&IdentifierParseInput::build(input)),
+        _ => None,
+    };
+
+    Diagnosis {
+        identifier_type,
+        type_id: identifier_type.id(),
+        uri: identifier.to_uri(),
+        near_miss_type: near_miss.map(|(near_miss_type, _)| near_miss_type),
+        reason: near_miss.map(|(_, reason)| reason),
+        warning,
+    }
+}
+
+#[cfg(test)]
+mod diagnosis_tests {
+    use super::*;
+
+    #[test]
+    fn recognised() {
+        let diagnosis = Identifier::diagnose("https://orcid.org/0000-0002-1694-233X");
+
+        assert_eq!(diagnosis.identifier_type, IdentifierType::Orcid);
+        assert_eq!(diagnosis.type_id, 2);
+        assert_eq!(
+            diagnosis.uri,
+            Some(String::from("https://orcid.org/0000-0002-1694-233X"))
+        );
+        assert_eq!(diagnosis.near_miss_type, None);
+        assert_eq!(diagnosis.reason, None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn near_miss_orcid() {
+        let diagnosis = Identifier::diagnose("https://orcid.org/0000-0002-1694-2330");
+
+        assert_eq!(diagnosis.identifier_type, IdentifierType::Uri);
+        assert_eq!(diagnosis.near_miss_type, Some(IdentifierType::Orcid));
+        assert_eq!(diagnosis.reason, Some("bad check digit"));
+    }
+
+    #[test]
+    fn near_miss_isbn() {
+        let diagnosis = Identifier::diagnose("0306406150");
+
+        assert_eq!(diagnosis.near_miss_type, Some(IdentifierType::Isbn));
+        assert_eq!(diagnosis.reason, Some("bad check digit"));
+    }
+
+    #[test]
+    fn near_miss_ror() {
+        let diagnosis = Identifier::diagnose("https://ror.org/02mhbdp99");
+
+        assert_eq!(diagnosis.near_miss_type, Some(IdentifierType::Ror));
+        assert_eq!(diagnosis.reason, Some("bad check digit"));
+    }
+
+    #[test]
+    fn near_miss_doi_registrant() {
+        let diagnosis = Identifier::diagnose("https://doi.org/10.0555/12345678");
+
+        assert_eq!(diagnosis.near_miss_type, Some(IdentifierType::Doi));
+        assert_eq!(
+            diagnosis.reason,
+            Some("registrant code has a leading zero or is too short")
+        );
+    }
+
+    #[test]
+    fn warning_doi_double_encoding() {
+        let diagnosis = Identifier::diagnose("https://doi.org/10.5555/a%252Fb");
+
+        assert_eq!(diagnosis.identifier_type, IdentifierType::Doi);
+        assert_eq!(diagnosis.warning, Some("possible double percent-encoding"));
+
+        assert_eq!(
+            Identifier::diagnose("https://doi.org/10.5555/a%2Fb").warning,
+            None
+        );
+        assert_eq!(
+            Identifier::diagnose("10.5555/a%2Fb").warning,
+            None,
+            "A percent sign in a raw DOI is literal."
+        );
+    }
+
+    #[test]
+    fn not_near_miss() {
+        let diagnosis = Identifier::diagnose("hello world");
+
+        assert_eq!(diagnosis.identifier_type, IdentifierType::String);
+        assert_eq!(diagnosis.uri, None);
+        assert_eq!(diagnosis.near_miss_type, None);
+        assert_eq!(diagnosis.reason, None);
+    }
+
+    #[test]
+    fn trace_near_miss_isbn() {
+        let (identifier, trace) = Identifier::parse_with_trace("0306406150");
+
+        assert_eq!(identifier, Identifier::parse("0306406150"));
+        assert_eq!(
+            trace.rejection(IdentifierType::Isbn),
+            Some("bad check digit")
+        );
+        assert_eq!(trace.rejection(IdentifierType::Doi), Some("no 10. prefix"));
+    }
+
+    #[test]
+    fn trace_recognised() {
+        let (identifier, trace) = Identifier::parse_with_trace("https://ror.org/02twcfp32");
+
+        assert_eq!(identifier, Identifier::Ror(String::from("02twcfp32")));
+        assert_eq!(
+            trace.attempts.last(),
+            Some(&ParseAttempt {
+                identifier_type: IdentifierType::Ror,
+                rejection: None
+            }),
+            "Trace should end with the parser that recognised the input."
+        );
+    }
+
+    #[test]
+    fn trace_fall_through() {
+        let (identifier, trace) = Identifier::parse_with_trace("hello world");
+
+        assert_eq!(identifier, Identifier::String(String::from("hello world")));
+        assert_eq!(trace.attempts.len(), PARSERS.len());
+        assert!(trace
+            .attempts
+            .iter()
+            .all(|attempt| attempt.rejection.is_some()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_near_miss_orcid() {
+        assert_eq!(
+            Identifier::diagnose_json("https://orcid.org/0000-0002-1694-2330"),
+            r#"{"type":"uri","type_id":4,"uri":"https://orcid.org/0000-0002-1694-2330","near_miss_type":"orcid","reason":"bad check digit","warning":null}"#
+        );
+    }
+}
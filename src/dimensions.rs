@@ -0,0 +1,115 @@
+//! Dimensions publication ID
+//! See <https://www.dimensions.ai>
+//!
+//! Dimensions identifies publications with IDs like "pub.1234567890". The bare form is short, so it's only recognised
+//! when it exactly fits the pattern.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Host of the Dimensions web app.
+const HOST: &str = "app.dimensions.ai";
+
+/// Path of publication pages in the Dimensions web app, which precedes the ID.
+const PUBLICATION_PATH: &str = "details/publication/";
+
+lazy_static! {
+    static ref DIMENSIONS_RE: Regex = Regex::new(r"^pub\.\d+$").unwrap();
+}
+
+/// Parse an input string as a Dimensions publication ID.
+///
+/// Accepts:
+///  - Bare ID, e.g. "pub.1234567890".
+///  - URL in the Dimensions app, e.g. "https://app.dimensions.ai/details/publication/pub.1234567890".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if DIMENSIONS_RE.is_match(&input.raw) {
+        Some(Identifier::Dimensions(String::from(input.raw.as_ref())))
+    } else if input.host_lowercase()?.eq(HOST) {
+        input.path_no_slash().and_then(|path| {
+            path.strip_prefix(PUBLICATION_PATH)
+                .filter(|id| DIMENSIONS_RE.is_match(id))
+                .map(|id| Identifier::Dimensions(String::from(id)))
+        })
+    } else {
+        None
+    }
+}
+
+/// Encode a Dimensions publication ID as a URI in the Dimensions app.
+/// Will always return a result if a Dimensions type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Dimensions(value) => {
+            Some(format!("https://{}/{}{}", HOST, PUBLICATION_PATH, value))
+        }
+        _ => None,
+    }
+}
+
+/// Encode a Dimensions publication ID as a stable string, without the app URL.
+/// Will always return a String if a Dimensions type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Dimensions(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod dimensions_parser_tests {
+    use super::*;
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::Dimensions(String::from("pub.1234567890")),
+            Identifier::parse("pub.1234567890")
+        );
+    }
+
+    #[test]
+    fn url() {
+        assert_eq!(
+            Identifier::Dimensions(String::from("pub.1234567890")),
+            Identifier::parse("https://app.dimensions.ai/details/publication/pub.1234567890")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_ne!(
+            Identifier::parse("pub.example").identifier_type(),
+            crate::identifiers::IdentifierType::Dimensions,
+            "Unrelated pub. strings are not recognised."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "https://app.dimensions.ai/details/grant/pub.1234567890"
+            )),
+            Identifier::parse("https://app.dimensions.ai/details/grant/pub.1234567890"),
+            "Only publication pages are recognised."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod dimensions_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://app.dimensions.ai/details/publication/pub.1234567890"
+            )),
+            Identifier::parse("pub.1234567890").to_uri()
+        );
+    }
+}
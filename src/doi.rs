@@ -1,40 +1,116 @@
 //! DOI
 
-use std::collections::HashSet;
-use std::fmt::Write;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Write;
 
+use crate::compact;
+use crate::extract;
 use crate::identifiers::{Identifier, IdentifierParseInput};
 use lazy_static::lazy_static;
 use regex::Regex;
 
+/// Hosts of DOI resolvers, which can be trusted to carry a DOI in a query parameter.
+const RESOLVER_HOSTS: &[&str] = &["doi.org", "dx.doi.org", "www.doi.org"];
+
+/// DOI prefix for Crossref Funder IDs.
+const FUNDER_PREFIX: &str = "10.13039";
+
+/// DOI prefixes reserved for testing and examples, which shouldn't appear in real metadata.
+/// "10.5555" is the DOI Foundation's test prefix, and "10.5072" is DataCite's former test prefix.
+const TEST_PREFIXES: &[&str] = &["10.5555", "10.5072"];
+
+/// DOI prefixes for RAiDs, Research Activity Identifiers, which identify research projects.
+/// See <https://raid.org>.
+const RAID_PREFIXES: &[&str] = &["10.80368"];
+
+/// Resolver for RAiDs, which shows project metadata rather than redirecting.
+const RAID_RESOLVER: &str = "https://raid.org";
+
+/// DOI prefixes for IGSNs, which identify physical samples, mainly in the geosciences.
+/// IGSNs were migrated to DOIs registered by IGSN e.V. through DataCite. See <https://www.igsn.org>.
+const IGSN_PREFIXES: &[&str] = &["10.58052"];
+
+/// Resolver for IGSNs.
+const IGSN_RESOLVER: &str = "https://igsn.org";
+
+/// Registrant codes of ISBN-A prefixes, which are shorter than other registrant codes.
+const ISBN_A_REGISTRANTS: &[&str] = &["978", "979"];
+
+/// Pattern for a DOI prefix, e.g. "10.5555". Prefixes may be subdivided with dots, e.g. the ISBN-A prefix
+/// "10.978.0306". The registrant code is checked separately by [`valid_registrant`].
+const PREFIX_PATTERN: &str = r"10\.\d+(?:\.\d+)*";
+
 lazy_static! {
 
     /// Match for various kinds of URI scheme that may be used in a DOI URI.
     /// Because of the variety of presentations of DOIs, it's possible to find a DOI like "http://doi.org/urn:doi:10.5555/12345678"
     /// in the wild. So the URI prefixes are removed from both the start of the whole string and the start of the path.
-    static ref URI_PREFIXES_SCHEME: Regex = Regex::new(r"^(https://|http://|doi:|urn:doi:|info:doi:)").unwrap();
+    static ref URI_PREFIXES_SCHEME: Regex = Regex::new(r"^(?i)(https://|http://|doi:|urn:doi:|info:doi:|hdl:|info:hdl/)").unwrap();
 
     /// Match for hostnames of DOI resolvers.
-    static ref URI_PREFIXES_HOST: Regex = Regex::new(r"^(dx.doi.org/|doi.org/)").unwrap();
+    /// The www.doi.org host also serves non-DOI pages, but those don't match the DOI syntax once the host is removed.
+    /// DOIs are Handles, so the Handle resolver also resolves them. Other Handles don't match the DOI syntax.
+    static ref URI_PREFIXES_HOST: Regex = Regex::new(r"^(?i)(dx\.doi\.org/|www\.doi\.org/|doi\.org/|hdl\.handle\.net/)").unwrap();
 
     /// Match a potential DOI with an encoded slash, anchored to the start of the string.
-    static ref DOI_RE : Regex = Regex::new(r"^10\.\d+(/|%2f).*").unwrap();
+    static ref DOI_RE : Regex = Regex::new(&format!(r"^(?i){}(/|%2f).*", PREFIX_PATTERN)).unwrap();
+
+    /// Match a DOI prefix on its own.
+    static ref DOI_PREFIX_RE : Regex = Regex::new(&format!(r"^{}$", PREFIX_PATTERN)).unwrap();
 
     /// Match a potential DOI strictly, anchored to the start of the string.
-    static ref DOI_STRICT_RE : Regex = Regex::new(r"^(10\.\d+)/(.+)$").unwrap();
+    static ref DOI_STRICT_RE : Regex = Regex::new(&format!(r"^({})/(.+)$", PREFIX_PATTERN)).unwrap();
+
+    /// Match a potential DOI anywhere in free text, running until whitespace.
+    static ref DOI_CANDIDATE_RE : Regex = Regex::new(&format!(r"\b{}/\S+", PREFIX_PATTERN)).unwrap();
+
+    /// Match a UUID anywhere in a DOI suffix. Suffixes are lower-case unless `preserve_doi_case` is set.
+    static ref UUID_RE : Regex = Regex::new(r"(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}").unwrap();
 
     /// From RFC 3986 section 2.3 Unreserved Characters
-    static ref UNRESERVED_CHARACTERS : HashSet<char> = HashSet::from_iter("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_.~".chars());
+    static ref UNRESERVED_CHARACTERS : BTreeSet<char> = BTreeSet::from_iter("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_.~".chars());
 
     /// From RFC 3986 section 2.2 Reserved Characters
-    static ref RESERVED_CHARACTERS : HashSet<char> = HashSet::from_iter("!$&'()*,/:;=@".chars());
+    static ref RESERVED_CHARACTERS : BTreeSet<char> = BTreeSet::from_iter("!$&'()*,/:;=@".chars());
 
     /// These characters should not be encoded in a DOI. All others must be.
-    static ref DO_NOT_ENCODE : HashSet<char> = HashSet::from_iter(UNRESERVED_CHARACTERS.union(&RESERVED_CHARACTERS).copied());
+    static ref DO_NOT_ENCODE : BTreeSet<char> = BTreeSet::from_iter(UNRESERVED_CHARACTERS.union(&RESERVED_CHARACTERS).copied());
+}
+
+/// Reasons that an identifier couldn't be encoded as a URI, from [`Identifier::try_to_uri`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EncodeError {
+    /// The identifier has no URI representation, e.g. a plain string or a custom scheme.
+    NoUri,
+
+    /// Writing the encoded DOI suffix failed.
+    Format,
+}
+
+impl From<fmt::Error> for EncodeError {
+    fn from(_: fmt::Error) -> Self {
+        EncodeError::Format
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::NoUri => write!(f, "identifier has no URI representation"),
+            EncodeError::Format => write!(f, "failed to encode DOI suffix"),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
 /// Percent-encode characters according to the specific rules for DOI encoding.
-fn percent_encode_for_doi(input: &str) -> String {
+fn percent_encode_for_doi(input: &str) -> Result<String, fmt::Error> {
     let mut result_buffer = String::new();
 
     // For multi-byte sequences. Unicode has a maximum character size of 4 bytes.
@@ -48,36 +124,81 @@ fn percent_encode_for_doi(input: &str) -> String {
             // Number of bytes used.
             let size = c.encode_utf8(&mut char_buffer).len();
 
+            // The buffer is the correct size for any Unicode character, so this shouldn't fail, but report it if it
+            // does.
             for b in char_buffer[0..size].iter() {
-                // The buffer is the correct size for any Unicode character. If
-                // there's a failure to write, then this should be a noisy
-                // error.
-                write!(&mut result_buffer, "%{:02X}", b).unwrap();
+                write!(&mut result_buffer, "%{:02X}", b)?;
             }
         }
     }
 
-    result_buffer
+    Ok(result_buffer)
 }
 
 // Construct an Identifier containing Unicode-native string.
-fn construct(decoded_raw_doi: &str) -> Option<Identifier> {
+// The suffix is lower-cased unless `preserve_case` is set.
+fn construct(decoded_raw_doi: &str, preserve_case: bool) -> Option<Identifier> {
     // If the input didn't start with "10." then the input was in the wrong format.
-    if let Some(matched) = DOI_STRICT_RE.captures(decoded_raw_doi) {
-        let prefix = matched.get(1).unwrap().as_str();
-        let suffix = matched.get(2).unwrap().as_str();
+    // The prefix can't contain a slash, so split on the first one rather than allocating capture groups.
+    if let Some((prefix, suffix)) = decoded_raw_doi
+        .split_once('/')
+        .filter(|(prefix, _)| DOI_STRICT_RE.is_match(decoded_raw_doi) && valid_registrant(prefix))
+    {
+        Some(Identifier::Doi {
+            prefix: String::from(prefix),
+            suffix: if preserve_case {
+                String::from(suffix)
+            } else {
+                suffix.to_lowercase()
+            },
+        })
+    } else {
+        None
+    }
+}
+
+/// Check the registrant code, the first element after "10." in the prefix. Registrant codes were allocated from 1000,
+/// so one with a leading zero or fewer than four digits is malformed, e.g. "10.0555" or "10.55". The exceptions are the
+/// ISBN-A prefixes, e.g. "10.978.0306", which have a three digit registrant.
+fn valid_registrant(prefix: &str) -> bool {
+    let registrant = prefix
+        .strip_prefix("10.")
+        .and_then(|rest| rest.split('.').next())
+        .unwrap_or_default();
 
-        let lowercase_suffix = suffix.to_lowercase();
+    ISBN_A_REGISTRANTS.contains(&registrant)
+        || (registrant.len() >= 4 && !registrant.starts_with('0'))
+}
+
+/// Find potential DOIs in free text, for extraction. Each candidate still needs to be parsed with [`try_parse`].
+pub(crate) fn candidates(text: &str) -> impl Iterator<Item = regex::Match<'_>> {
+    DOI_CANDIDATE_RE.find_iter(text)
+}
 
+/// Construct a DOI from a separate prefix and suffix, without any of the URL handling of [`try_parse`].
+/// The suffix is lower-cased.
+pub(crate) fn from_parts(prefix: &str, suffix: &str) -> Option<Identifier> {
+    // Like the strict regex, the suffix must be non-empty and on one line.
+    if DOI_PREFIX_RE.is_match(prefix)
+        && valid_registrant(prefix)
+        && !suffix.is_empty()
+        && !suffix.contains('\n')
+    {
         Some(Identifier::Doi {
             prefix: String::from(prefix),
-            suffix: lowercase_suffix,
+            suffix: suffix.to_lowercase(),
         })
     } else {
         None
     }
 }
 
+/// Is the input a URL on a DOI resolver, with or without a scheme, e.g. "doi.org/10.5555/12345678"?
+/// Only these have a URL fragment to remove, as a raw DOI may contain a "#".
+fn is_resolver_url(input: &str) -> bool {
+    URI_PREFIXES_HOST.is_match(&URI_PREFIXES_SCHEME.replace(input, ""))
+}
+
 // Remove the string prefixes for DOIs. Not DOI prefixes. Urgh.
 fn remove_doi_prefixes(input: &str) -> String {
     // Remove leading scheme from start of string, if present.
@@ -103,16 +224,66 @@ fn remove_doi_prefixes(input: &str) -> String {
 /// If a URL DOI is incorrectly encoded, don't try to guess, just return as an
 /// invalid DOI. To guess would be to break the resolvability of the identifier,
 /// making it worse than useless.
+///
+/// A "+" in a URL DOI is a literal "+", per the DOI handbook, not a space as in HTML form encoding.
+/// Only "%20" decodes to a space.
+///
+/// DOIs are case-insensitive, so the suffix is lower-cased, which allows DOIs to be compared for equality. If the
+/// `preserve_doi_case` option is set, the case of the suffix is kept, at the cost of case-insensitive comparison.
+///
+/// If the `trim_doi_trailing_punctuation` option is set, see [`try_parse_trim_trailing_punctuation`].
 pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
-    // DOIs are case-invariant so always lower-case them.
-    let lowercase = input.raw.to_lowercase();
+    if input.options.trim_doi_trailing_punctuation {
+        try_parse_trim_trailing_punctuation(input)
+    } else {
+        try_parse_exact(input)
+    }
+}
+
+/// Parse an input string as a DOI, first with trailing punctuation removed, e.g. "10.5555/12345678." becomes
+/// "10.5555/12345678". If that isn't a DOI, the whole input is tried.
+/// Unbalanced closing brackets are punctuation, but balanced ones are kept, as they're common in SICI suffixes.
+pub(crate) fn try_parse_trim_trailing_punctuation(
+    input: &IdentifierParseInput,
+) -> Option<Identifier> {
+    let trimmed = extract::trim_trailing(&input.raw);
+
+    if trimmed.len() < input.raw.len() {
+        try_parse_exact(&input.with_raw(trimmed)).or_else(|| try_parse_exact(input))
+    } else {
+        try_parse_exact(input)
+    }
+}
+
+/// Parse an input string as a DOI, without removing punctuation.
+fn try_parse_exact(input: &IdentifierParseInput) -> Option<Identifier> {
+    let preserve_case = input.options.preserve_doi_case;
+
+    // DOIs are case-invariant so lower-case them, unless asked not to.
+    // The prefix matchers are case-insensitive, so work with either.
+    let normalised = if preserve_case {
+        Cow::Borrowed(input.raw.as_ref())
+    } else {
+        input.raw_lowercase()
+    };
 
     // Raw DOIs can be encoded and put into a URI.
-    if DOI_STRICT_RE.is_match(&lowercase) {
-        construct(&lowercase)
+    if DOI_STRICT_RE.is_match(&normalised) {
+        construct(&normalised, preserve_case)
     } else {
         // Otherwise treat this as a URI DOI, and attempt to parse.
-        let less_prefixes = remove_doi_prefixes(&lowercase);
+        let less_prefixes = remove_doi_prefixes(&normalised);
+
+        // A fragment on a resolver URL, e.g. "#page=3", isn't part of the DOI. A "#" in a DOI is encoded in a URL, so
+        // this doesn't remove any of the DOI. Raw DOIs are matched above, so keep a "#", which can be part of a SICI.
+        let less_prefixes = if is_resolver_url(&normalised) {
+            less_prefixes
+                .split_once('#')
+                .map(|(doi, _)| String::from(doi))
+                .unwrap_or(less_prefixes)
+        } else {
+            less_prefixes
+        };
 
         if DOI_RE.is_match(&less_prefixes) {
             // Use [`percent_encoding::percent_decode`] rather than
@@ -121,8 +292,21 @@ pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
             //
             // It's better to report invavlid DOIs than try to rescue them and end up
             // with an unintended string.
+            if !valid_percent_escapes(&less_prefixes) {
+                log::error!("Malformed percent-encoding: {}", &less_prefixes);
+                return None;
+            }
+
             match percent_encoding::percent_decode(less_prefixes.as_bytes()).decode_utf8() {
-                Ok(decoded) => construct(&decoded),
+                Ok(decoded) => {
+                    // Decoding again would be guessing, and the encoder produces this for a suffix with a "%" in it,
+                    // so only warn. See [`warning`].
+                    if has_percent_escape(&decoded) {
+                        log::warn!("Possible double percent-encoding: {}", &less_prefixes);
+                    }
+
+                    construct(&decoded, preserve_case)
+                }
                 Err(err) => {
                     log::error!(
                         "Failed to decode URI component: {}, error: {}",
@@ -133,11 +317,143 @@ pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
                 }
             }
         } else {
-            None
+            try_parse_resolver_query(input).or_else(|| try_parse_compact(input))
         }
     }
 }
 
+/// Parse a DOI from the "doi" query parameter of a URL on a known DOI resolver, e.g.
+/// "https://doi.org/resolve?doi=10.5555/12345678".
+///
+/// Only resolver hosts are trusted. Publisher landing pages often carry DOIs in query parameters, but those are
+/// landing pages, not DOIs.
+///
+/// As in the path, a "+" is treated as a literal character, not a space.
+fn try_parse_resolver_query(input: &IdentifierParseInput) -> Option<Identifier> {
+    let host = input.host_lowercase()?;
+
+    if !RESOLVER_HOSTS.contains(&host.as_str()) {
+        return None;
+    }
+
+    let value = input
+        .query()?
+        .split('&')
+        .find_map(|pair| match pair.split_once('=') {
+            Some((key, value)) if key.eq_ignore_ascii_case("doi") => Some(value),
+            _ => None,
+        })?;
+
+    // As with the path, fail on malformed escapes or invalid UTF-8 rather than rescue them.
+    if !valid_percent_escapes(value) {
+        return None;
+    }
+
+    let decoded = percent_encoding::percent_decode(value.as_bytes())
+        .decode_utf8()
+        .ok()?;
+
+    if has_percent_escape(&decoded) {
+        log::warn!("Possible double percent-encoding: {}", value);
+    }
+
+    construct(&decoded, input.options.preserve_doi_case)
+}
+
+/// Check that every "%" is followed by two hex digits.
+/// [`percent_encoding::percent_decode`] passes a truncated or non-hex escape such as "%2" or "%zz" through as literal
+/// characters, which would be guessing at what was meant.
+fn valid_percent_escapes(input: &str) -> bool {
+    let bytes = input.as_bytes();
+
+    bytes.iter().enumerate().all(|(index, byte)| {
+        *byte != b'%'
+            || bytes
+                .get(index + 1..index + 3)
+                .is_some_and(|escape| escape.iter().all(u8::is_ascii_hexdigit))
+    })
+}
+
+/// Does the input contain a "%" followed by two hex digits, escaping a character that isn't unreserved?
+/// After decoding a URL once, this is a sign that it may have been percent-encoded twice, e.g. "%252F" for "/". It's
+/// also what [`try_to_uri`] produces for a suffix containing "%2F", so it can't be rejected. Unreserved characters are
+/// never encoded in practice, so e.g. "12%34" is more likely a real percent sign than an encoded "4".
+fn has_percent_escape(input: &str) -> bool {
+    input.as_bytes().windows(3).any(|window| {
+        window[0] == b'%'
+            && core::str::from_utf8(&window[1..])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .is_some_and(|byte| !UNRESERVED_CHARACTERS.contains(&char::from(byte)))
+    })
+}
+
+/// Parse a DOI from a compact identifier on identifiers.org, e.g. "https://identifiers.org/doi:10.5555/12345678".
+/// The local identifier is treated as it would be on a DOI resolver, so it's URL-encoded.
+fn try_parse_compact(input: &IdentifierParseInput) -> Option<Identifier> {
+    let url = compact::unwrap(input, "doi", "https://doi.org/")?;
+    try_parse_exact(&input.with_raw(&url))
+}
+
+/// Near-miss reason for a prefix that fails [`valid_registrant`].
+const MALFORMED_REGISTRANT: &str = "registrant code has a leading zero or is too short";
+
+/// If the input looks like a DOI but isn't valid, explain why.
+pub(crate) fn near_miss(input: &IdentifierParseInput) -> Option<&'static str> {
+    let lowercase = input.raw_lowercase();
+    let less_prefixes = remove_doi_prefixes(&lowercase);
+
+    if DOI_STRICT_RE.is_match(&lowercase) {
+        return match lowercase.split_once('/') {
+            Some((prefix, _)) if !valid_registrant(prefix) => Some(MALFORMED_REGISTRANT),
+            _ => None,
+        };
+    }
+
+    if !DOI_RE.is_match(&less_prefixes) {
+        return None;
+    }
+
+    if !valid_percent_escapes(&less_prefixes) {
+        return Some("malformed percent-encoding");
+    }
+
+    match percent_encoding::percent_decode(less_prefixes.as_bytes()).decode_utf8() {
+        Ok(decoded) if construct(&decoded, false).is_none() => match decoded.split_once('/') {
+            Some((prefix, _)) if !valid_registrant(prefix) => Some(MALFORMED_REGISTRANT),
+            _ => Some("malformed suffix"),
+        },
+        Ok(_) => None,
+        Err(_) => Some("invalid UTF-8 in percent-encoding"),
+    }
+}
+
+/// Warning for a DOI whose suffix still contains a percent escape after decoding the URL.
+const DOUBLE_ENCODING: &str = "possible double percent-encoding";
+
+/// If the input is a DOI URL that may have been percent-encoded twice, e.g. "https://doi.org/10.5555/a%252Fb", say so.
+/// The DOI is still recognised with the suffix decoded once, e.g. "a%2fb", as decoding again would be guessing.
+pub(crate) fn warning(input: &IdentifierParseInput) -> Option<&'static str> {
+    let lowercase = input.raw_lowercase();
+
+    // Raw DOIs aren't decoded, so a "%" in one is literal.
+    if DOI_STRICT_RE.is_match(&lowercase) {
+        return None;
+    }
+
+    let less_prefixes = remove_doi_prefixes(&lowercase);
+
+    if !DOI_RE.is_match(&less_prefixes) || !valid_percent_escapes(&less_prefixes) {
+        return None;
+    }
+
+    percent_encoding::percent_decode(less_prefixes.as_bytes())
+        .decode_utf8()
+        .ok()
+        .filter(|decoded| has_percent_escape(decoded))
+        .map(|_| DOUBLE_ENCODING)
+}
+
 /// Encode a DOI according per "DOI Name Encoding Rules for URL Presentation" in the DOI handbook.
 /// https://www.doi.org/doi-handbook/HTML/encoding-rules-for-urls.html
 ///
@@ -147,22 +463,64 @@ pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
 /// 2. Encode all characters that are "mandatory" and "recommended" according to the DOI handbook.
 /// 3. Don't encode any RFC 3986 "reserved" character that fall outside these ranges.
 /// 4. Encode all other characters.
-pub fn to_uri(input: &Identifier) -> Option<String> {
+///
+/// Never panics. Returns an error if the identifier isn't a DOI or the suffix couldn't be encoded.
+pub(crate) fn try_to_uri(input: &Identifier) -> Result<String, EncodeError> {
     match input {
         Identifier::Doi {
             ref prefix,
             ref suffix,
         } => {
-            let encoded_suffix = percent_encode_for_doi(suffix);
-            Some(format!("https://doi.org/{}/{}", prefix, encoded_suffix))
+            let encoded_suffix = percent_encode_for_doi(suffix)?;
+            Ok(format!("https://doi.org/{}/{}", prefix, encoded_suffix))
         }
+        _ => Err(EncodeError::NoUri),
+    }
+}
+
+/// Encode a DOI as a URI on a resolver other than doi.org, with the same encoding as [`try_to_uri`].
+/// The resolver has no trailing slash, e.g. "https://raid.org".
+fn to_resolver_uri(input: &Identifier, resolver: &str) -> Option<String> {
+    match input {
+        Identifier::Doi {
+            ref prefix,
+            ref suffix,
+        } => percent_encode_for_doi(suffix)
+            .ok()
+            .map(|encoded_suffix| format!("{}/{}/{}", resolver, prefix, encoded_suffix)),
+        _ => None,
+    }
+}
+
+/// Encode a DOI as a URI. See [`try_to_uri`] for the encoding rules.
+/// Will always return a result if a DOI type is supplied.
+pub fn to_uri(input: &Identifier) -> Option<String> {
+    try_to_uri(input).ok()
+}
+
+/// Encode a DOI as an "info:doi:" URN, as required by some legacy systems such as OAI-PMH repositories.
+/// As this is a URN not a URL, the suffix isn't percent-encoded.
+/// Will always return a result if a DOI type is supplied.
+pub(crate) fn to_urn(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Doi {
+            ref prefix,
+            ref suffix,
+        } => Some(format!("info:doi:{}/{}", prefix, suffix)),
         _ => None,
     }
 }
 
-/// Encode a DOI as a stable simple string.
+/// Encode a DOI as a stable string, which is the URI.
+/// The suffix is percent-encoded, so the stable string is always ASCII, and suitable for ASCII-only database keys.
 /// Will always return a String if a DOI type is supplied.
 pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    to_uri(input)
+}
+
+/// The DOI name, i.e. "prefix/suffix" in native Unicode.
+/// Will always return a String if a DOI type is supplied.
+pub(crate) fn to_name(input: &Identifier) -> Option<String> {
     match input {
         Identifier::Doi {
             ref prefix,
@@ -172,10 +530,71 @@ pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
     }
 }
 
+/// Does the DOI suffix contain a UUID?
+/// Many DataCite DOIs are auto-minted with UUID suffixes, so this helps distinguish them from human-readable DOIs.
+/// Returns false for any non-DOI type.
+pub(crate) fn is_uuid_suffix(input: &Identifier) -> bool {
+    match input {
+        Identifier::Doi { prefix: _, suffix } => UUID_RE.is_match(suffix),
+        _ => false,
+    }
+}
+
+/// Does the DOI have one of the given prefixes?
+/// Returns false for any non-DOI type.
+fn has_prefix(input: &Identifier, prefixes: &[&str]) -> bool {
+    match input {
+        Identifier::Doi { prefix, suffix: _ } => prefixes.contains(&prefix.as_str()),
+        _ => false,
+    }
+}
+
+/// Is the DOI a Crossref Funder ID, in the Open Funder Registry?
+/// See <https://www.crossref.org/services/funder-registry/>.
+pub(crate) fn is_funder(input: &Identifier) -> bool {
+    has_prefix(input, &[FUNDER_PREFIX])
+}
+
+/// Is the DOI under a prefix reserved for testing and examples?
+pub(crate) fn is_test(input: &Identifier) -> bool {
+    has_prefix(input, TEST_PREFIXES)
+}
+
+/// Is the DOI a RAiD, a Research Activity Identifier?
+pub(crate) fn is_raid(input: &Identifier) -> bool {
+    has_prefix(input, RAID_PREFIXES)
+}
+
+/// Encode a RAiD as a URI on raid.org.
+/// Returns None unless a RAiD is supplied.
+pub(crate) fn to_raid_uri(input: &Identifier) -> Option<String> {
+    if is_raid(input) {
+        to_resolver_uri(input, RAID_RESOLVER)
+    } else {
+        None
+    }
+}
+
+/// Is the DOI an IGSN, a sample identifier?
+pub(crate) fn is_igsn(input: &Identifier) -> bool {
+    has_prefix(input, IGSN_PREFIXES)
+}
+
+/// Encode an IGSN as a URI on igsn.org.
+/// Returns None unless an IGSN is supplied.
+pub(crate) fn to_igsn_uri(input: &Identifier) -> Option<String> {
+    if is_igsn(input) {
+        to_resolver_uri(input, IGSN_RESOLVER)
+    } else {
+        None
+    }
+}
+
 /// Tests specifically for the parser.
 #[cfg(test)]
 mod doi_parser_tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn parse_simple_raw() {
@@ -188,6 +607,31 @@ mod doi_parser_tests {
         );
     }
 
+    /// Real prefixes of every shape are still accepted.
+    #[test]
+    fn registrants() {
+        let examples = [
+            ("10.1000/182", "10.1000"),
+            ("10.1038/nphys1170", "10.1038"),
+            ("10.13039/501100000780", "10.13039"),
+            ("10.80368/b1adfb3a", "10.80368"),
+            ("10.1000.10/123", "10.1000.10"),
+            ("10.978.0306/406157", "10.978.0306"),
+            ("10.979.10/12345", "10.979.10"),
+        ];
+
+        for (input, prefix) in examples.iter() {
+            assert_eq!(
+                Identifier::parse(input)
+                    .doi_parts()
+                    .map(|(prefix, _)| prefix),
+                Some(*prefix),
+                "Expected {} to be a DOI",
+                input
+            );
+        }
+    }
+
     #[test]
     fn parse_simple_doi_scheme() {
         assert_eq!(
@@ -221,6 +665,13 @@ mod doi_parser_tests {
             "URLs on the DOI resolver using HTTP should parse."
         );
 
+        // Resolver with www.
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.doi.org/10.5555/12345678"),
+            "URLs on the www DOI resolver should parse."
+        );
+
         // Old resolver http
         assert_eq!(
             expected,
@@ -236,6 +687,29 @@ mod doi_parser_tests {
         );
     }
 
+    /// The whole input is lower-cased before parsing, including the scheme and host.
+    #[test]
+    fn parse_doi_resolver_mixed_case() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12345678"),
+            },
+            Identifier::parse("HttpS://Doi.Org/10.5555/12345678"),
+            "Mixed-case scheme and host should parse."
+        );
+
+        // Lower-casing turns "%2F" into "%2f", which must still be decoded.
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("abc/def<ghi>"),
+            },
+            Identifier::parse("HTTPS://DOI.ORG/10.5555%2FABC%2FDEF%3CGHI%3E"),
+            "Upper-case hex encoding should survive lower-casing."
+        );
+    }
+
     #[test]
     fn parse_doi_schemes() {
         let expected = Identifier::Doi {
@@ -248,6 +722,95 @@ mod doi_parser_tests {
         assert_eq!(expected, Identifier::parse("urn:doi:10.5555/12345678"));
     }
 
+    /// DOIs are Handles, so the Handle resolver and "hdl:" scheme identify the same DOI.
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_handle() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("12345678"),
+        };
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://hdl.handle.net/10.5555/12345678")
+        );
+        assert_eq!(
+            expected,
+            Identifier::parse("http://hdl.handle.net/10.5555/12345678")
+        );
+        assert_eq!(expected, Identifier::parse("hdl:10.5555/12345678"));
+        assert_eq!(expected, Identifier::parse("info:hdl/10.5555/12345678"));
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://hdl.handle.net/2027/mdp.39015")),
+            Identifier::parse("https://hdl.handle.net/2027/mdp.39015"),
+            "Handles that aren't DOIs are still URIs."
+        );
+    }
+
+    /// A fragment on a resolver URL isn't part of the DOI, but a "#" in a raw DOI is.
+    #[test]
+    fn fragment() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("x"),
+            },
+            Identifier::parse("https://doi.org/10.5555/x#frag")
+        );
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12345678"),
+            },
+            Identifier::parse("doi.org/10.5555/12345678#page=3")
+        );
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("x#frag"),
+            },
+            Identifier::parse("10.5555/x#frag")
+        );
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("x#frag"),
+            },
+            Identifier::parse("https://doi.org/10.5555/x%23frag"),
+            "An encoded hash is part of the DOI."
+        );
+    }
+
+    /// The scheme is matched case-insensitively, and the suffix is still lower-cased.
+    #[test]
+    fn parse_doi_schemes_mixed_case() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("abc"),
+        };
+
+        let examples = [
+            "DOI:10.5555/AbC",
+            "Doi:10.5555/AbC",
+            "URN:DOI:10.5555/AbC",
+            "INFO:DOI:10.5555/AbC",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                expected,
+                Identifier::parse(example),
+                "Expected {} to be a DOI",
+                example
+            );
+        }
+    }
+
     #[test]
     fn lower_case() {
         let expected = Identifier::Doi {
@@ -295,6 +858,38 @@ mod doi_parser_tests {
         )
     }
 
+    /// The DOI parser works on the raw input, so doesn't depend on the input also being a valid URI.
+    #[test]
+    fn sici_without_uri() {
+        let inputs = [
+            "10.1002/(SICI)1099-050X(199823/24)37:3/4<197::AID-HRM2>3.0.CO;2-#",
+            "https://doi.org/10.1002/(sici)1099-050x(199823/24)37:3/4<197::aid-hrm2>3.0.co;2-%23",
+        ];
+
+        for input in inputs {
+            let parse_input = IdentifierParseInput::build(input);
+
+            #[cfg(feature = "std")]
+            assert!(
+                parse_input.uri().is_none(),
+                "Expected {} not to be a valid URI",
+                input
+            );
+
+            assert_eq!(
+                try_parse(&parse_input),
+                Some(Identifier::Doi {
+                    prefix: String::from("10.1002"),
+                    suffix: String::from(
+                        "(sici)1099-050x(199823/24)37:3/4<197::aid-hrm2>3.0.co;2-#"
+                    ),
+                }),
+                "Expected {} to parse as a DOI without a URI",
+                input
+            );
+        }
+    }
+
     /// Deal with URL encodings.
     #[test]
     fn mandatory_encodings() {
@@ -319,6 +914,37 @@ mod doi_parser_tests {
         );
     }
 
+    /// A "+" is literal, not a space.
+    #[test]
+    fn plus_sign() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("a+b"),
+            },
+            Identifier::parse("https://doi.org/10.5555/a+b"),
+            "Unencoded plus is a literal plus."
+        );
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("a+b"),
+            },
+            Identifier::parse("https://doi.org/10.5555/a%2Bb"),
+            "Encoded plus is a literal plus."
+        );
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("a b"),
+            },
+            Identifier::parse("https://doi.org/10.5555/a%20b"),
+            "Encoded space is a space."
+        );
+    }
+
     ///  DOI handbook specify recommended encoding of
     /// < %3C, > %3E, { %7B, } %7D, ^ %5E, [ %5B, ] %5D
     /// ` %60, | %7C, \ %5C, + %2B
@@ -356,6 +982,26 @@ mod doi_parser_tests {
         );
     }
 
+    /// Resolvers can carry the DOI in a query parameter.
+    #[test]
+    fn resolver_query() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("12345678"),
+        };
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://doi.org/resolve?doi=10.5555/12345678")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://dx.doi.org/resolve?type=x&DOI=10.5555%2F12345678"),
+            "Encoded DOI in query parameter with other parameters should parse."
+        );
+    }
+
     /// Test the boundaries of the regexes.
     #[test]
     fn regexes_valid() {
@@ -378,7 +1024,7 @@ mod doi_parser_tests {
                     prefix: _,
                     suffix: _,
                 } => {}
-                _ => assert!(false, "Should parse as a DOI"),
+                _ => panic!("Should parse as a DOI"),
             }
         }
     }
@@ -387,9 +1033,11 @@ mod doi_parser_tests {
 #[cfg(test)]
 mod doi_parser_negative_tests {
     use super::*;
+    use alloc::vec;
 
     /// Some URLs on the doi.org domain aren't DOIs.
     #[test]
+    #[cfg(feature = "std")]
     fn non_dois() {
         let url1 = "https://www.doi.org/the-identifier/what-is-a-doi/";
         assert_eq!(
@@ -406,8 +1054,49 @@ mod doi_parser_negative_tests {
         )
     }
 
+    /// Dots in resolver hosts are literal, so lookalike hosts aren't resolvers.
+    #[test]
+    fn lookalike_doi_hosts() {
+        let examples = [
+            "wwwXdoi.org/10.5555/1",
+            "https://wwwXdoi.org/10.5555/1",
+            "dxXdoiXorg/10.5555/1",
+            "doiXorg/10.5555/1",
+        ];
+
+        for example in examples.iter() {
+            assert!(
+                !matches!(Identifier::parse(example), Identifier::Doi { .. }),
+                "Should not parse {} as a DOI",
+                example
+            );
+        }
+    }
+
+    /// A lookalike of the Handle resolver host isn't a resolver URL, so has no fragment removed and isn't a DOI.
+    #[test]
+    fn lookalike_handle_host() {
+        assert!(is_resolver_url("https://hdl.handle.net/10.5555/1#page=3"));
+        assert!(!is_resolver_url("https://hdlXhandle.net/10.5555/1#page=3"));
+        assert!(!is_resolver_url("hdl.handleXnet/10.5555/1"));
+
+        for example in [
+            "hdlXhandle.net/10.5555/1",
+            "https://hdl.handleXnet/10.5555/1#page=3",
+        ]
+        .iter()
+        {
+            assert!(
+                !matches!(Identifier::parse(example), Identifier::Doi { .. }),
+                "Should not parse {} as a DOI",
+                example
+            );
+        }
+    }
+
     /// Some landing pages contain DOI strings, but should not be considered to be DOIs.
     #[test]
+    #[cfg(feature = "std")]
     fn landing_page() {
         let plos = "https://journals.plos.org/plosone/article?id=10.1371/journal.pone.0190046";
         assert_eq!(
@@ -416,6 +1105,13 @@ mod doi_parser_negative_tests {
             "Landing page is not a DOI."
         );
 
+        let publisher = "https://example.com/resolve?doi=10.5555/12345678";
+        assert_eq!(
+            Identifier::Uri(String::from(publisher)),
+            Identifier::parse(publisher),
+            "DOI query parameter is only trusted on resolver hosts."
+        );
+
         let wiley = "https://onlinelibrary.wiley.com/doi/10.1111/j.1751-0813.2010.00564.x";
         assert_eq!(
             Identifier::Uri(String::from(wiley)),
@@ -424,6 +1120,129 @@ mod doi_parser_negative_tests {
         );
     }
 
+    /// A shortDOI (e.g. "10/abcde") has no "10.<registrant>" prefix, so isn't a full DOI.
+    #[test]
+    fn short_doi_not_full_doi() {
+        for example in ["10/abcde", "https://doi.org/10/abcde"] {
+            assert!(
+                !matches!(Identifier::parse(example), Identifier::Doi { .. }),
+                "Should not parse shortDOI {} as a full DOI",
+                example
+            );
+        }
+    }
+
+    /// A "%" must be followed by two hex digits. Truncated or non-hex escapes aren't passed through.
+    #[test]
+    fn malformed_percent_escapes() {
+        let examples = [
+            "https://doi.org/10.5555/%2",
+            "https://doi.org/10.5555/%zz",
+            "https://doi.org/10.5555/1234%2g5678",
+            "https://doi.org/resolve?doi=10.5555/%zz",
+        ];
+
+        for example in examples.iter() {
+            assert!(
+                !matches!(Identifier::parse(example), Identifier::Doi { .. }),
+                "Should not parse {} as a DOI",
+                example
+            );
+        }
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12%34")
+            },
+            Identifier::parse("https://doi.org/10.5555/12%2534"),
+            "Well-formed escape of a percent sign should still be decoded."
+        );
+    }
+
+    /// Decoding twice would be guessing, so DOIs that may be percent-encoded twice are only decoded once.
+    #[test]
+    fn double_percent_encoding() {
+        let examples = [
+            ("https://doi.org/10.5555/a%252Fb", "a%2fb"),
+            ("https://doi.org/10.5555/a%253Cb%253E", "a%3cb%3e"),
+            ("https://doi.org/resolve?doi=10.5555/a%252Fb", "a%2fb"),
+        ];
+
+        for (example, suffix) in examples.iter() {
+            assert_eq!(
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from(*suffix)
+                },
+                Identifier::parse(example),
+                "Should decode {} once",
+                example
+            );
+        }
+
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("a/b")
+            },
+            Identifier::parse("https://doi.org/10.5555/a%2Fb"),
+            "Single encoding should still be decoded."
+        );
+    }
+
+    /// The stable string of a DOI with a percent sign in its suffix encodes it, and must load back as the same DOI.
+    #[test]
+    fn percent_in_suffix_round_trip() {
+        let examples = ["10.5555/a%2Fb", "10.5555/100%25off"];
+
+        for example in examples.iter() {
+            let identifier = Identifier::parse(example);
+            assert!(
+                matches!(identifier, Identifier::Doi { .. }),
+                "Expected {} to be a DOI",
+                example
+            );
+
+            let (value, type_id) = identifier.to_id_string_pair();
+
+            assert_eq!(
+                Identifier::from_id_string_pair(&value, type_id),
+                Some(Identifier::parse(example)),
+                "Expected {} to round-trip via {}",
+                example,
+                value
+            );
+            assert_eq!(Identifier::parse(&value), identifier);
+        }
+    }
+
+    /// Registrant codes start at 1000, so a leading zero or fewer than four digits is malformed.
+    #[test]
+    fn malformed_registrant() {
+        let examples = [
+            "10.0555/x",
+            "10.05/x",
+            "10.555/x",
+            "10.0555.1/x",
+            "https://doi.org/10.0555/x",
+            "doi:10.0555/x",
+        ];
+
+        for example in examples.iter() {
+            assert!(
+                !matches!(Identifier::parse(example), Identifier::Doi { .. }),
+                "Should not parse {} as a DOI",
+                example
+            );
+        }
+
+        assert!(
+            Identifier::doi("10.0555", "x").is_err(),
+            "Constructor should also reject malformed registrant codes."
+        );
+    }
+
     /// Test the boundaries of the regexes for negative cases.
     #[test]
     fn regexes_invalid() {
@@ -449,12 +1268,302 @@ mod doi_parser_negative_tests {
                 suffix: _,
             } = parsed
             {
-                assert!(false, "Should not parse {} as a DOI", &example)
+                panic!("Should not parse {} as a DOI", &example)
             }
         }
     }
 }
 
+#[cfg(test)]
+mod doi_uuid_suffix_tests {
+    use super::*;
+    use crate::identifiers::ParseOptions;
+
+    #[test]
+    fn uuid_suffix() {
+        assert!(
+            Identifier::parse("10.5072/fk2-3f2504e0-4f89-11d3-9a0c-0305e82c3301")
+                .is_uuid_suffix_doi(),
+            "UUID in suffix should be detected."
+        );
+
+        assert!(
+            Identifier::parse("https://doi.org/10.5072/3F2504E0-4F89-11D3-9A0C-0305E82C3301")
+                .is_uuid_suffix_doi(),
+            "Upper-case UUID in URL form should be detected."
+        );
+
+        let options = ParseOptions {
+            preserve_doi_case: true,
+            ..Default::default()
+        };
+
+        assert!(
+            Identifier::parse_with_options(
+                "10.5072/3F2504E0-4F89-11D3-9A0C-0305E82C3301",
+                &options
+            )
+            .is_uuid_suffix_doi(),
+            "Upper-case UUID should be detected when the case is preserved."
+        );
+    }
+
+    #[test]
+    fn non_uuid_suffix() {
+        assert!(
+            !Identifier::parse("10.1002/(SICI)1099-050X(199823/24)37:3/4<197::AID-HRM2>3.0.CO;2-#")
+                .is_uuid_suffix_doi(),
+            "SICI suffix is not a UUID."
+        );
+
+        assert!(
+            !Identifier::parse("10.1103/physrevlett.103.157203").is_uuid_suffix_doi(),
+            "Journal-style suffix is not a UUID."
+        );
+
+        assert!(
+            !Identifier::parse("https://ror.org/02twcfp32").is_uuid_suffix_doi(),
+            "Non-DOI is never a UUID-suffix DOI."
+        );
+    }
+}
+
+#[cfg(test)]
+mod doi_preserve_case_tests {
+    use super::*;
+    use crate::identifiers::ParseOptions;
+
+    fn preserve_case() -> ParseOptions {
+        ParseOptions {
+            preserve_doi_case: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn preserved() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("AbC"),
+        };
+
+        assert_eq!(
+            expected,
+            Identifier::parse_with_options("10.5555/AbC", &preserve_case())
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse_with_options("HTTPS://DOI.ORG/10.5555/AbC", &preserve_case()),
+            "Suffix case should be preserved in URL form, regardless of host case."
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse_with_options("DOI:10.5555%2FAbC", &preserve_case()),
+            "Suffix case should be preserved with a scheme and encoded slash."
+        );
+    }
+
+    #[test]
+    fn lowercased_by_default() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("abc"),
+            },
+            Identifier::parse("10.5555/AbC")
+        );
+    }
+}
+
+#[cfg(test)]
+mod doi_trailing_punctuation_tests {
+    use super::*;
+    use crate::identifiers::ParseOptions;
+
+    fn trim_punctuation() -> ParseOptions {
+        ParseOptions {
+            trim_doi_trailing_punctuation: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn kept_by_default() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12345678."),
+            },
+            Identifier::parse("10.5555/12345678."),
+            "The full stop could be part of the DOI."
+        );
+    }
+
+    #[test]
+    fn trimmed() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("12345678"),
+        };
+
+        let examples = [
+            "10.5555/12345678.",
+            "10.5555/12345678,",
+            "10.5555/12345678;",
+            "10.5555/12345678)",
+            "10.5555/12345678).",
+            "https://doi.org/10.5555/12345678.",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                expected,
+                Identifier::parse_with_options(example, &trim_punctuation()),
+                "Expected punctuation to be removed from {}",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn balanced_brackets() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("abc(1)"),
+            },
+            Identifier::parse_with_options("10.5555/abc(1).", &trim_punctuation()),
+            "Balanced brackets are part of the DOI."
+        );
+    }
+
+    #[test]
+    fn whole_input_if_trimmed_is_not_doi() {
+        assert_eq!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("."),
+            },
+            Identifier::parse_with_options("10.5555/.", &trim_punctuation()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod doi_funder_tests {
+    use super::*;
+
+    #[test]
+    fn funder() {
+        assert!(Identifier::parse("10.13039/100000001").is_funder_doi());
+        assert!(Identifier::parse("https://doi.org/10.13039/501100000780").is_funder_doi());
+    }
+
+    #[test]
+    fn non_funder() {
+        assert!(!Identifier::parse("10.5555/12345678").is_funder_doi());
+
+        assert!(
+            !Identifier::parse("10.130391/100000001").is_funder_doi(),
+            "Prefix must match exactly."
+        );
+
+        assert!(!Identifier::parse("https://ror.org/02twcfp32").is_funder_doi());
+    }
+}
+
+#[cfg(test)]
+mod doi_test_prefix_tests {
+    use super::*;
+
+    #[test]
+    fn test_doi() {
+        assert!(Identifier::parse("10.5555/12345678").is_test_doi());
+        assert!(Identifier::parse("https://doi.org/10.5072/abc").is_test_doi());
+    }
+
+    #[test]
+    fn non_test_doi() {
+        assert!(!Identifier::parse("10.1038/nature12373").is_test_doi());
+
+        assert!(
+            !Identifier::parse("10.55551/12345678").is_test_doi(),
+            "Prefix must match exactly."
+        );
+
+        assert!(!Identifier::parse("https://ror.org/02twcfp32").is_test_doi());
+    }
+}
+
+#[cfg(test)]
+mod doi_raid_tests {
+    use super::*;
+
+    #[test]
+    fn raid() {
+        let raid = Identifier::parse("https://doi.org/10.80368/b1adfb3a");
+
+        assert!(raid.is_raid());
+        assert_eq!(
+            Some(String::from("https://raid.org/10.80368/b1adfb3a")),
+            raid.to_raid_uri()
+        );
+
+        assert_eq!(
+            Some(String::from("https://doi.org/10.80368/b1adfb3a")),
+            raid.to_uri(),
+            "A RAiD is still a DOI, and resolves as one."
+        );
+    }
+
+    #[test]
+    fn non_raid() {
+        let doi = Identifier::parse("10.5555/12345678");
+
+        assert!(!doi.is_raid());
+        assert_eq!(None, doi.to_raid_uri());
+
+        assert!(!Identifier::parse("https://ror.org/02twcfp32").is_raid());
+    }
+}
+
+#[cfg(test)]
+mod doi_igsn_tests {
+    use super::*;
+
+    #[test]
+    fn igsn() {
+        let igsn = Identifier::parse("10.58052/IEUHB0002");
+
+        assert!(igsn.is_igsn());
+        assert_eq!(
+            Some(String::from("https://igsn.org/10.58052/ieuhb0002")),
+            igsn.to_igsn_uri()
+        );
+
+        assert_eq!(
+            Some(String::from("https://doi.org/10.58052/ieuhb0002")),
+            igsn.to_uri(),
+            "An IGSN is still a DOI, and resolves as one."
+        );
+    }
+
+    #[test]
+    fn non_igsn() {
+        let doi = Identifier::parse("10.5555/12345678");
+
+        assert!(!doi.is_igsn());
+        assert_eq!(None, doi.to_igsn_uri());
+
+        assert!(
+            !Identifier::parse("10.80368/b1adfb3a").is_igsn(),
+            "A RAiD is not an IGSN."
+        );
+    }
+}
+
 /// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
 #[cfg(test)]
 mod doi_end_to_end_tests {
@@ -469,6 +1578,27 @@ mod doi_end_to_end_tests {
         );
     }
 
+    /// DOIs with a mixed-case scheme round-trip to the same DOI, via the URI and the stable string.
+    #[test]
+    fn mixed_case_scheme_round_trip() {
+        for example in ["DOI:10.5555/AbC", "URN:DOI:10.5555/AbC"].iter() {
+            let parsed = Identifier::parse(example);
+
+            assert_eq!(
+                Some(String::from("https://doi.org/10.5555/abc")),
+                parsed.to_uri()
+            );
+
+            assert_eq!(parsed, Identifier::parse(&parsed.to_uri().unwrap()));
+
+            let (stable_string, type_id) = parsed.to_id_string_pair();
+            assert_eq!(
+                Some(parsed),
+                Identifier::from_id_string_pair(&stable_string, type_id)
+            );
+        }
+    }
+
     /// The full test for parsing encoded URLs in prior test cases.
     /// Test that a URL in the recommended format is end-tripped to be identical.
     #[test]
@@ -478,4 +1608,109 @@ mod doi_end_to_end_tests {
 
         assert_eq!(correct, Identifier::parse(correct).to_uri().unwrap());
     }
+
+    /// Slashes in the suffix are reserved characters, so are left unencoded in the URL, and parsed back as slashes.
+    /// This includes a suffix that starts with a slash, giving a double slash.
+    #[test]
+    fn slash_round_trip() {
+        let examples = [
+            (
+                "10.5555/abc/def",
+                "https://doi.org/10.5555/abc/def",
+                "abc/def",
+            ),
+            ("10.5555//abc", "https://doi.org/10.5555//abc", "/abc"),
+        ];
+
+        for (input, expected_uri, expected_suffix) in examples.iter() {
+            let parsed = Identifier::parse(input);
+            assert_eq!(
+                parsed,
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from(*expected_suffix),
+                }
+            );
+
+            let uri = parsed.to_uri().unwrap();
+            assert_eq!(uri, *expected_uri);
+
+            assert_eq!(
+                Identifier::parse(&uri),
+                parsed,
+                "Expected {} to round-trip via the URI",
+                input
+            );
+        }
+
+        assert_eq!(
+            Identifier::parse("https://doi.org/10.5555%2Fabc%2fdef"),
+            Identifier::parse("10.5555/abc/def"),
+            "Encoded slashes are equivalent."
+        );
+    }
+
+    /// The stable string is ASCII, even when the suffix isn't.
+    #[test]
+    fn ascii_stable_string() {
+        let doi = Identifier::parse("10.5555/1234e®🄮™5678");
+        let stable = doi.to_stable_string();
+
+        assert!(stable.is_ascii(), "Expected {} to be ASCII", stable);
+        assert_eq!(Identifier::parse(&stable), doi);
+    }
+
+    /// The DOI name is decoded from the URL form.
+    #[test]
+    fn doi_name() {
+        let name = "10.5555/1234e®🄮™5678";
+        let uri = Identifier::parse(name).to_uri().unwrap();
+
+        assert_ne!(name, uri, "URL form should be encoded.");
+        assert_eq!(Some(String::from(name)), Identifier::parse(&uri).doi_name());
+
+        assert_eq!(None, Identifier::parse("https://example.com").doi_name());
+    }
+
+    #[test]
+    fn doi_parts() {
+        assert_eq!(
+            Some(("10.5555", "1234%5678")),
+            Identifier::parse("https://doi.org/10.5555/1234%255678").doi_parts(),
+            "Parts should be decoded from the URL form."
+        );
+
+        assert_eq!(None, Identifier::parse("https://example.com").doi_parts());
+        assert_eq!(None, Identifier::parse("0306406152").doi_parts());
+    }
+
+    /// Every byte of a 4-byte UTF-8 character is encoded.
+    #[test]
+    fn four_byte_suffix() {
+        let doi = Identifier::parse("10.5555/🦆");
+
+        assert_eq!(
+            try_to_uri(&doi),
+            Ok(String::from("https://doi.org/10.5555/%F0%9F%A6%86"))
+        );
+
+        assert_eq!(
+            try_to_uri(&Identifier::parse("https://example.com")),
+            Err(EncodeError::NoUri)
+        );
+    }
+
+    #[test]
+    fn urn() {
+        assert_eq!(
+            Some(String::from("info:doi:10.5555/12345678")),
+            Identifier::parse("https://doi.org/10.5555/12345678").to_urn()
+        );
+
+        assert_eq!(
+            Some(String::from("info:doi:10.5555/<>{}^[]`|\\+")),
+            Identifier::parse("https://doi.org/10.5555/%3C%3E%7B%7D%5E%5B%5D%60%7C%5C%2B").to_urn(),
+            "URN should not be percent-encoded."
+        );
+    }
 }
@@ -0,0 +1,285 @@
+//! Extraction
+//!
+//! Find identifiers embedded in free text, e.g. "See DOI 10.5555/12345678 for details".
+//!
+//! The patterns here are unanchored versions of those in each type's module, and DOI candidates come from the doi
+//! module itself. They only find candidates, and each candidate is then validated by the normal parser for its type.
+//! They are deliberately conservative, to avoid false positives in prose. For example, DOIs need the "10." prefix, and ISBNs need an "ISBN" label or a 978 or 979 prefix.
+
+use crate::identifiers::{Identifier, IdentifierParseInput, IdentifierType};
+use crate::{doi, isbn, orcid, ror};
+use alloc::vec::Vec;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Characters that often follow an identifier in prose, but are unlikely to end one.
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"'];
+
+lazy_static! {
+    /// ORCID iD, with or without the resolver, or bare with spaces instead of hyphens.
+    static ref ORCID_RE: Regex = Regex::new(r"(?i)\b(?:(?:https?://orcid\.org/)?\d{4}-\d{4}-\d{4}-\d{3}[\dX]|\d{4} \d{4} \d{4} \d{3}[\dX])\b").unwrap();
+
+    /// ROR ID, only with the resolver, as the bare form is too ambiguous.
+    static ref ROR_RE: Regex = Regex::new(r"(?i)\bhttps?://ror\.org/0[a-z0-9]{8}\b").unwrap();
+
+    /// ISBN with a label. Group 1 is the ISBN.
+    static ref LABELLED_ISBN_RE: Regex = Regex::new(r"\bISBN(?:-1[03])?:?\s*(\d[\d -]{8,15}[\dXx])\b").unwrap();
+
+    /// 13 digit ISBN with a Bookland prefix, without a label.
+    static ref BOOKLAND_ISBN_RE: Regex = Regex::new(r"\b97[89]-?(\d-?){9}\d\b").unwrap();
+}
+
+/// Find all identifiers in the text, in the order they appear.
+/// Where candidates overlap, the first one found is kept.
+pub(crate) fn extract_all(text: &str) -> Vec<Identifier> {
+    // Start and end of each match, with the identifier.
+    let mut found: Vec<(usize, usize, Identifier)> = Vec::new();
+
+    for candidate in doi::candidates(text) {
+        let trimmed = trim_trailing(candidate.as_str());
+        if let Some(identifier) = doi::try_parse(&IdentifierParseInput::build(trimmed)) {
+            found.push((
+                candidate.start(),
+                candidate.start() + trimmed.len(),
+                identifier,
+            ));
+        }
+    }
+
+    for candidate in ORCID_RE.find_iter(text) {
+        if let Some(identifier) = orcid::try_parse(&IdentifierParseInput::build(candidate.as_str()))
+        {
+            found.push((candidate.start(), candidate.end(), identifier));
+        }
+    }
+
+    for candidate in ROR_RE.find_iter(text) {
+        if let Some(identifier) = ror::try_parse(&IdentifierParseInput::build(candidate.as_str())) {
+            found.push((candidate.start(), candidate.end(), identifier));
+        }
+    }
+
+    for candidate in LABELLED_ISBN_RE
+        .captures_iter(text)
+        .filter_map(|groups| groups.get(1))
+    {
+        let trimmed = candidate.as_str().trim_end_matches([' ', '-']);
+        if let Some(identifier) = isbn::try_parse(&IdentifierParseInput::build(trimmed)) {
+            found.push((candidate.start(), candidate.end(), identifier));
+        }
+    }
+
+    for candidate in BOOKLAND_ISBN_RE.find_iter(text) {
+        if let Some(identifier) = isbn::try_parse(&IdentifierParseInput::build(candidate.as_str()))
+        {
+            found.push((candidate.start(), candidate.end(), identifier));
+        }
+    }
+
+    // Order by position, stable so that earlier types win ties, then drop overlaps.
+    found.sort_by_key(|(start, _, _)| *start);
+
+    let mut result = Vec::new();
+    let mut last_end = 0;
+    for (start, end, identifier) in found {
+        if result.is_empty() || start >= last_end {
+            result.push(identifier);
+            last_end = end;
+        }
+    }
+
+    result
+}
+
+/// Find the most valuable identifier in the text, by type: DOI, then ORCID, then ROR, then ISBN.
+/// Of several identifiers of that type, the first is chosen.
+pub(crate) fn best_match(text: &str) -> Option<Identifier> {
+    extract_all(text)
+        .into_iter()
+        .min_by_key(|identifier| match identifier.identifier_type() {
+            IdentifierType::Doi => 0,
+            IdentifierType::Orcid => 1,
+            IdentifierType::Ror => 2,
+            _ => 3,
+        })
+}
+
+/// Remove punctuation that ends a sentence or clause, and closing brackets without a matching opening bracket.
+/// DOI suffixes can contain brackets, e.g. SICIs, so balanced brackets are kept.
+pub(crate) fn trim_trailing(candidate: &str) -> &str {
+    let mut result = candidate;
+
+    loop {
+        let trimmed = result.trim_end_matches(TRAILING_PUNCTUATION);
+
+        let trimmed = match trimmed.chars().last() {
+            Some(close @ (')' | ']' | '}')) => {
+                let open = match close {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+
+                if trimmed.matches(close).count() > trimmed.matches(open).count() {
+                    &trimmed[..trimmed.len() - 1]
+                } else {
+                    trimmed
+                }
+            }
+            _ => trimmed,
+        };
+
+        if trimmed.len() == result.len() {
+            return result;
+        }
+
+        result = trimmed;
+    }
+}
+
+#[cfg(test)]
+mod best_match_tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn doi_wins() {
+        let expected = Some(Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("12345678"),
+        });
+
+        assert_eq!(
+            Identifier::best_match("ISBN 0-306-40615-2, doi:10.5555/12345678."),
+            expected
+        );
+
+        assert_eq!(
+            Identifier::best_match(
+                "Jane Doe | https://orcid.org/0000-0002-1694-233X | https://doi.org/10.5555/12345678"
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(
+            Identifier::best_match("ISBN 0-306-40615-2 https://ror.org/02twcfp32"),
+            Some(Identifier::Ror(String::from("02twcfp32")))
+        );
+
+        assert_eq!(
+            Identifier::best_match("ISBN 0-306-40615-2, ISBN 978-1-86197-876-9"),
+            Some(Identifier::Isbn(String::from("9780306406157"))),
+            "First of the same type should be chosen."
+        );
+
+        assert_eq!(
+            Identifier::best_match("ISBN 978-1-86197-876-9, ISBN 0-306-40615-2"),
+            Some(Identifier::Isbn(String::from("9781861978769"))),
+            "First of the same type should be chosen."
+        );
+    }
+
+    #[test]
+    fn nothing() {
+        assert_eq!(Identifier::best_match("Nothing to see here."), None);
+    }
+}
+
+#[cfg(test)]
+mod extract_tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn sentence() {
+        assert_eq!(
+            Identifier::extract_all(
+                "See DOI 10.5555/12345678 for details, by https://orcid.org/0000-0002-1694-233X."
+            ),
+            [
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from("12345678"),
+                },
+                Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            ]
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(
+            Identifier::extract_all(
+                "(Published as doi:10.5555/abc(1)def.) Funded by https://ror.org/02twcfp32, ISBN 0-306-40615-2."
+            ),
+            [
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from("abc(1)def"),
+                },
+                Identifier::Ror(String::from("02twcfp32")),
+                Identifier::Isbn(String::from("9780306406157")),
+            ],
+            "Trailing punctuation and unbalanced brackets should be removed."
+        );
+    }
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::extract_all("Author 0000-0002-1694-233X wrote 978-0-306-40615-7."),
+            [
+                Identifier::Orcid(String::from("0000-0002-1694-233X")),
+                Identifier::Isbn(String::from("9780306406157")),
+            ]
+        );
+    }
+
+    #[test]
+    fn spaced_orcid() {
+        assert_eq!(
+            Identifier::extract_all("Author ORCID 0000 0002 1694 233X, corresponding."),
+            [Identifier::Orcid(String::from("0000-0002-1694-233X"))]
+        );
+    }
+
+    #[test]
+    fn isbn_a() {
+        assert_eq!(
+            Identifier::extract_all("Also registered as 10.978.0306/406152."),
+            [Identifier::Doi {
+                prefix: String::from("10.978.0306"),
+                suffix: String::from("406152"),
+            }],
+            "Subdivided ISBN-A prefixes should be extracted."
+        );
+    }
+
+    #[test]
+    fn conservative() {
+        let examples = [
+            // No 10. prefix.
+            "Version 2.5555/1234 was released.",
+            // Malformed registrant code.
+            "Version 10.55/1234 was released.",
+            // Invalid ORCID check digit.
+            "Author 0000-0002-1694-2330.",
+            // Numbers without an ISBN label or Bookland prefix.
+            "Call 0306406152 for details.",
+            // Bad ISBN check digit.
+            "ISBN 0-306-40615-3",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::extract_all(example),
+                [],
+                "Expected nothing in {}",
+                example
+            );
+        }
+    }
+}
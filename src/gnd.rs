@@ -0,0 +1,138 @@
+//! GND, Gemeinsame Normdatei (Integrated Authority File)
+//! See <https://www.dnb.de/EN/Professionell/Standardisierung/GND/gnd_node.html>
+//!
+//! An authority file for people, organisations, subjects and works, maintained by the German National Library and
+//! widely used in European library metadata. GND IDs are resolved at "https://d-nb.info/gnd/", and in MARC records
+//! they're labelled "(DE-588)".
+//!
+//! GND IDs are represented in the bare form, e.g. "118540238". As a bare ID is too ambiguous to parse, the stable
+//! string is the MARC form.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Host of the German National Library resolver.
+const HOST: &str = "d-nb.info";
+
+/// Path of GND records on the resolver, which precedes the GND ID.
+const GND_PATH: &str = "gnd/";
+
+/// MARC organisation code of the GND.
+const MARC_PREFIX: &str = "(DE-588)";
+
+lazy_static! {
+    /// Syntax of a GND ID, per the DNB. Some older IDs have a hyphen before the check character.
+    static ref GND_RE: Regex = Regex::new(r"^(1[0-3]?\d{7}[0-9X]|[47]\d{6}-\d|[1-9]\d{0,7}-[0-9X]|3\d{7}[0-9X])$").unwrap();
+}
+
+/// Parse an input string as a GND ID.
+///
+/// Accepts:
+///  - Resolver URL, e.g. "https://d-nb.info/gnd/118540238".
+///  - MARC form, e.g. "(DE-588)118540238".
+///
+/// Bare IDs aren't recognised, as they're too ambiguous.
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if let Some(id) = input.raw.strip_prefix(MARC_PREFIX) {
+        construct(id.trim_start())
+    } else if input.host_lowercase()?.eq(HOST) {
+        input
+            .path_no_slash()
+            .and_then(|path| path.strip_prefix(GND_PATH).and_then(construct))
+    } else {
+        None
+    }
+}
+
+fn construct(id: &str) -> Option<Identifier> {
+    let upcase = id.to_uppercase();
+
+    if GND_RE.is_match(&upcase) {
+        Some(Identifier::Gnd(upcase))
+    } else {
+        None
+    }
+}
+
+/// Encode a GND ID as a URI on the German National Library resolver.
+/// Will always return a result if a GND type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Gnd(value) => Some(format!("https://{}/{}{}", HOST, GND_PATH, value)),
+        _ => None,
+    }
+}
+
+/// Encode a GND ID as a stable string, in the MARC form, e.g. "(DE-588)118540238".
+/// Will always return a String if a GND type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Gnd(value) => Some(format!("{}{}", MARC_PREFIX, value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod gnd_parser_tests {
+    use super::*;
+
+    #[test]
+    fn url() {
+        assert_eq!(
+            Identifier::Gnd(String::from("118540238")),
+            Identifier::parse("https://d-nb.info/gnd/118540238")
+        );
+
+        assert_eq!(
+            Identifier::Gnd(String::from("4074335-4")),
+            Identifier::parse("http://d-nb.info/gnd/4074335-4"),
+            "Older IDs have a hyphen."
+        );
+    }
+
+    #[test]
+    fn marc() {
+        assert_eq!(
+            Identifier::Gnd(String::from("118540238")),
+            Identifier::parse("(DE-588)118540238")
+        );
+
+        assert_eq!(
+            Identifier::Gnd(String::from("10153740X")),
+            Identifier::parse("(DE-588)10153740x"),
+            "Check character should be upper-cased."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_eq!(
+            Identifier::Uri(String::from("https://d-nb.info/gnd/abc")),
+            Identifier::parse("https://d-nb.info/gnd/abc")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com/gnd/118540238")),
+            Identifier::parse("https://example.com/gnd/118540238"),
+            "Other hosts are not recognised."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod gnd_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://d-nb.info/gnd/118540238")),
+            Identifier::parse("(DE-588)118540238").to_uri()
+        );
+    }
+}
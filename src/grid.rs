@@ -0,0 +1,120 @@
+//! GRID, Global Research Identifier Database
+//! See <https://www.grid.ac>
+//!
+//! A legacy identifier for research organisations, e.g. "grid.5072.0". GRID was retired in favour of ROR, but GRID IDs
+//! are still found in older datasets.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts of the grid.ac website.
+const HOSTS: &[&str] = &["grid.ac", "www.grid.ac"];
+
+/// Path of institute pages on grid.ac, which precedes the GRID ID.
+const INSTITUTES_PATH: &str = "institutes/";
+
+lazy_static! {
+    static ref GRID_RE: Regex = Regex::new(r"^grid\.\d+\.\d+$").unwrap();
+}
+
+/// Parse an input string as a GRID ID.
+///
+/// Accepts:
+///  - Bare GRID ID, e.g. "grid.5072.0".
+///  - URL on grid.ac, e.g. "https://www.grid.ac/institutes/grid.5072.0".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let lowercase = input.raw_lowercase();
+
+    if GRID_RE.is_match(&lowercase) {
+        Some(Identifier::Grid(lowercase.into_owned()))
+    } else if HOSTS.contains(&input.host_lowercase()?.as_str()) {
+        input
+            .path_no_slash()
+            .map(|path| path.to_lowercase())
+            .and_then(|path| path.strip_prefix(INSTITUTES_PATH).map(String::from))
+            .filter(|id| GRID_RE.is_match(id))
+            .map(Identifier::Grid)
+    } else {
+        None
+    }
+}
+
+/// Encode a GRID ID as a URI on grid.ac.
+/// Will always return a result if a GRID type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Grid(value) => Some(format!("https://www.grid.ac/institutes/{}", value)),
+        _ => None,
+    }
+}
+
+/// Encode a GRID ID as a stable string, without the website.
+/// Will always return a String if a GRID type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Grid(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod grid_parser_tests {
+    use super::*;
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::Grid(String::from("grid.5072.0")),
+            Identifier::parse("grid.5072.0")
+        );
+    }
+
+    #[test]
+    fn url() {
+        let expected = Identifier::Grid(String::from("grid.5072.0"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.grid.ac/institutes/grid.5072.0")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://grid.ac/institutes/grid.5072.0"),
+            "Host without www should be recognised."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_eq!(
+            Identifier::Uri(String::from("grid.5072")),
+            Identifier::parse("grid.5072"),
+            "No final part."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com/institutes/grid.5072.0")),
+            Identifier::parse("https://example.com/institutes/grid.5072.0"),
+            "Other hosts are not recognised."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod grid_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://www.grid.ac/institutes/grid.5072.0")),
+            Identifier::parse("grid.5072.0").to_uri()
+        );
+    }
+}
@@ -1,11 +1,33 @@
-use std::str::FromStr;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::str::FromStr;
 
-use crate::{doi, isbn, orcid, ror, uri};
+#[cfg(feature = "agency")]
+use crate::agency;
+#[cfg(feature = "agency")]
+pub use crate::agency::Agency;
+use crate::diagnostics;
+pub use crate::diagnostics::{Diagnosis, ParseAttempt, ParseTrace};
+pub use crate::doi::EncodeError;
+use crate::extract;
+use crate::{
+    accession, ark, dimensions, doi, gnd, grid, isbn, ismn, isrc, istc, iswc, oclc, orcid, pdb,
+    purl, researcherid, ror, semanticscholar, shortdoi, swhid, uri, urn_nbn,
+};
+#[cfg(feature = "std")]
 use http::Uri;
 
 /// A Scholarly Identifier.
 /// Each type of scholarly identifier has a different purpose, different semantics for construction, different validation and comparison.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Identifier {
     /// DOI, Digital Object Identifier
     ///
@@ -53,37 +75,669 @@ pub enum Identifier {
     /// Always expressed in the 13-digit form, including check-digit.
     /// Hyphens and spaces are removed.
     Isbn(String),
+
+    /// ARK, Archival Resource Key
+    /// Split into the NAAN (Name Assigning Authority Number) and the name, without the resolver.
+    /// ARKs are case-sensitive, so the case of the name is preserved.
+    Ark { naan: String, name: String },
+
+    /// ISMN, International Standard Music Number
+    /// Always expressed in the 13-digit form, including check-digit.
+    /// Hyphens and spaces are removed.
+    Ismn(String),
+
+    /// ResearcherID, the Web of Science (formerly Publons) author identifier
+    /// A raw identifier in upper case, without the link resolver, e.g. "A-1234-2010".
+    ResearcherId(String),
+
+    /// SWHID, Software Heritage persistent identifier
+    /// The full SWHID, without the resolver, including any qualifiers.
+    Swhid(String),
+
+    /// GRID, Global Research Identifier Database id.
+    /// A legacy identifier for research organisations, superseded by ROR. A raw identifier, without the website.
+    Grid(String),
+
+    /// OCLC control number, as used by WorldCat.
+    /// Expressed as the number, without prefix or leading zeros.
+    Oclc(String),
+
+    /// GND, Gemeinsame Normdatei (Integrated Authority File) id.
+    /// A bare identifier, without the resolver or MARC prefix.
+    Gnd(String),
+
+    /// Dimensions publication ID.
+    /// A raw identifier, without the app URL, e.g. "pub.1234567890".
+    Dimensions(String),
+
+    /// Semantic Scholar paper identifier.
+    /// Either a Corpus ID with its prefix, e.g. "CorpusID:12345678", or a 40 hex digit paper ID, without the website.
+    SemanticScholar(String),
+
+    /// PURL, Persistent Uniform Resource Locator.
+    /// The whole URL, normalised to https, e.g. "https://purl.org/dc/terms/title".
+    Purl(String),
+
+    /// ISRC, International Standard Recording Code
+    /// Upper case, in the hyphenated layout, e.g. "US-RC1-17-00274".
+    Isrc(String),
+
+    /// An identifier recognised by a [`CustomParser`], e.g. an in-house repository ID.
+    /// The scheme is the name of the parser's scheme, e.g. "grant", and the value is as returned by the parser.
+    Custom { scheme: String, value: String },
+
+    /// ISTC, International Standard Text Code
+    /// Upper case, with hyphens and spaces removed, e.g. "0A9200212B4A1057".
+    Istc(String),
+
+    /// INSDC or RefSeq sequence accession number
+    /// As written, including any version, e.g. "NM_001301717" or "AB123456.1".
+    Accession(String),
+
+    /// shortDOI, an alias for a DOI
+    /// Lower case, e.g. "10/abcde".
+    ShortDoi(String),
+
+    /// ISWC, International Standard Musical Work Code
+    /// In the canonical form, e.g. "T-034.524.680-1".
+    Iswc(String),
+
+    /// PDB ID, Protein Data Bank identifier
+    /// Classic IDs in upper case, e.g. "1ABC", and other extended IDs in lower case, e.g. "pdb_10001abc".
+    Pdb(String),
+
+    /// URN:NBN, National Bibliography Number
+    /// The full URN, with the prefix and namespace in lower case, e.g. "urn:nbn:de:bsz:93-opus-59016".
+    UrnNbn(String),
+}
+
+// Stable numeric type ids, as used by `to_id_string_pair` and `from_id_string_pair`.
+// These are defined to be stable, and should not be altered.
+pub const TYPE_ID_DOI: u32 = 1;
+pub const TYPE_ID_ORCID: u32 = 2;
+pub const TYPE_ID_ROR: u32 = 3;
+pub const TYPE_ID_URI: u32 = 4;
+pub const TYPE_ID_STRING: u32 = 5;
+pub const TYPE_ID_ISBN: u32 = 6;
+pub const TYPE_ID_ARK: u32 = 7;
+pub const TYPE_ID_ISMN: u32 = 8;
+pub const TYPE_ID_RESEARCHERID: u32 = 9;
+pub const TYPE_ID_SWHID: u32 = 10;
+pub const TYPE_ID_GRID: u32 = 11;
+pub const TYPE_ID_OCLC: u32 = 12;
+pub const TYPE_ID_GND: u32 = 13;
+pub const TYPE_ID_DIMENSIONS: u32 = 14;
+pub const TYPE_ID_SEMANTICSCHOLAR: u32 = 15;
+pub const TYPE_ID_PURL: u32 = 16;
+pub const TYPE_ID_ISRC: u32 = 17;
+pub const TYPE_ID_CUSTOM: u32 = 18;
+pub const TYPE_ID_ISTC: u32 = 19;
+pub const TYPE_ID_ACCESSION: u32 = 20;
+pub const TYPE_ID_SHORTDOI: u32 = 21;
+pub const TYPE_ID_ISWC: u32 = 22;
+pub const TYPE_ID_PDB: u32 = 23;
+pub const TYPE_ID_URNNBN: u32 = 24;
+
+/// The type of an Identifier, without its value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum IdentifierType {
+    Doi,
+    Orcid,
+    Ror,
+    Uri,
+    String,
+    Isbn,
+    Ark,
+    Ismn,
+    ResearcherId,
+    Swhid,
+    Grid,
+    Oclc,
+    Gnd,
+    Dimensions,
+    SemanticScholar,
+    Purl,
+    Isrc,
+    Custom,
+    Istc,
+    Accession,
+    ShortDoi,
+    Iswc,
+    Pdb,
+    UrnNbn,
+}
+
+impl IdentifierType {
+    /// The stable numeric type id, as used in [`Identifier::to_id_string_pair`].
+    pub fn id(&self) -> u32 {
+        match self {
+            IdentifierType::Doi => TYPE_ID_DOI,
+            IdentifierType::Orcid => TYPE_ID_ORCID,
+            IdentifierType::Ror => TYPE_ID_ROR,
+            IdentifierType::Uri => TYPE_ID_URI,
+            IdentifierType::String => TYPE_ID_STRING,
+            IdentifierType::Isbn => TYPE_ID_ISBN,
+            IdentifierType::Ark => TYPE_ID_ARK,
+            IdentifierType::Ismn => TYPE_ID_ISMN,
+            IdentifierType::ResearcherId => TYPE_ID_RESEARCHERID,
+            IdentifierType::Swhid => TYPE_ID_SWHID,
+            IdentifierType::Grid => TYPE_ID_GRID,
+            IdentifierType::Oclc => TYPE_ID_OCLC,
+            IdentifierType::Gnd => TYPE_ID_GND,
+            IdentifierType::Dimensions => TYPE_ID_DIMENSIONS,
+            IdentifierType::SemanticScholar => TYPE_ID_SEMANTICSCHOLAR,
+            IdentifierType::Purl => TYPE_ID_PURL,
+            IdentifierType::Isrc => TYPE_ID_ISRC,
+            IdentifierType::Custom => TYPE_ID_CUSTOM,
+            IdentifierType::Istc => TYPE_ID_ISTC,
+            IdentifierType::Accession => TYPE_ID_ACCESSION,
+            IdentifierType::ShortDoi => TYPE_ID_SHORTDOI,
+            IdentifierType::Iswc => TYPE_ID_ISWC,
+            IdentifierType::Pdb => TYPE_ID_PDB,
+            IdentifierType::UrnNbn => TYPE_ID_URNNBN,
+        }
+    }
+
+    /// All identifier types, in order of stable type id.
+    pub fn all() -> &'static [IdentifierType] {
+        &[
+            IdentifierType::Doi,
+            IdentifierType::Orcid,
+            IdentifierType::Ror,
+            IdentifierType::Uri,
+            IdentifierType::String,
+            IdentifierType::Isbn,
+            IdentifierType::Ark,
+            IdentifierType::Ismn,
+            IdentifierType::ResearcherId,
+            IdentifierType::Swhid,
+            IdentifierType::Grid,
+            IdentifierType::Oclc,
+            IdentifierType::Gnd,
+            IdentifierType::Dimensions,
+            IdentifierType::SemanticScholar,
+            IdentifierType::Purl,
+            IdentifierType::Isrc,
+            IdentifierType::Custom,
+            IdentifierType::Istc,
+            IdentifierType::Accession,
+            IdentifierType::ShortDoi,
+            IdentifierType::Iswc,
+            IdentifierType::Pdb,
+            IdentifierType::UrnNbn,
+        ]
+    }
+
+    /// Short lower-case name, e.g. "doi". The same as the serde representation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            IdentifierType::Doi => "doi",
+            IdentifierType::Orcid => "orcid",
+            IdentifierType::Ror => "ror",
+            IdentifierType::Uri => "uri",
+            IdentifierType::String => "string",
+            IdentifierType::Isbn => "isbn",
+            IdentifierType::Ark => "ark",
+            IdentifierType::Ismn => "ismn",
+            IdentifierType::ResearcherId => "researcherid",
+            IdentifierType::Swhid => "swhid",
+            IdentifierType::Grid => "grid",
+            IdentifierType::Oclc => "oclc",
+            IdentifierType::Gnd => "gnd",
+            IdentifierType::Dimensions => "dimensions",
+            IdentifierType::SemanticScholar => "semanticscholar",
+            IdentifierType::Purl => "purl",
+            IdentifierType::Isrc => "isrc",
+            IdentifierType::Custom => "custom",
+            IdentifierType::Istc => "istc",
+            IdentifierType::Accession => "accession",
+            IdentifierType::ShortDoi => "shortdoi",
+            IdentifierType::Iswc => "iswc",
+            IdentifierType::Pdb => "pdb",
+            IdentifierType::UrnNbn => "urnnbn",
+        }
+    }
+}
+
+/// A parser for one type of identifier.
+/// Each type has a zero-sized implementation, so that parsers can be listed and iterated over.
+pub(crate) trait Parser {
+    /// Attempt to parse the input as this type.
+    fn try_parse(&self, input: &IdentifierParseInput) -> Option<Identifier>;
+
+    /// The type that this parser produces.
+    fn identifier_type(&self) -> IdentifierType;
+
+    /// The stable type id of the type that this parser produces.
+    fn type_id(&self) -> u32 {
+        self.identifier_type().id()
+    }
+}
+
+/// Declare a zero-sized parser that delegates to a module's `try_parse` function.
+macro_rules! parser {
+    ($parser:ident, $identifier_type:ident, $module:ident) => {
+        pub(crate) struct $parser;
+
+        impl Parser for $parser {
+            fn try_parse(&self, input: &IdentifierParseInput) -> Option<Identifier> {
+                $module::try_parse(input)
+            }
+
+            fn identifier_type(&self) -> IdentifierType {
+                IdentifierType::$identifier_type
+            }
+        }
+    };
 }
 
-/// Signature of a function that attempts to parse to an Identifier.
-type IdentifierParser = fn(input: &IdentifierParseInput) -> Option<Identifier>;
+parser!(DoiParser, Doi, doi);
+parser!(OrcidParser, Orcid, orcid);
+parser!(IsmnParser, Ismn, ismn);
+parser!(IsbnParser, Isbn, isbn);
+parser!(RorParser, Ror, ror);
+parser!(ResearcherIdParser, ResearcherId, researcherid);
+parser!(ArkParser, Ark, ark);
+parser!(SwhidParser, Swhid, swhid);
+parser!(GridParser, Grid, grid);
+parser!(OclcParser, Oclc, oclc);
+parser!(GndParser, Gnd, gnd);
+parser!(DimensionsParser, Dimensions, dimensions);
+parser!(SemanticScholarParser, SemanticScholar, semanticscholar);
+parser!(PurlParser, Purl, purl);
+parser!(IsrcParser, Isrc, isrc);
+parser!(IstcParser, Istc, istc);
+parser!(AccessionParser, Accession, accession);
+parser!(ShortDoiParser, ShortDoi, shortdoi);
+parser!(IswcParser, Iswc, iswc);
+parser!(PdbParser, Pdb, pdb);
+parser!(UrnNbnParser, UrnNbn, urn_nbn);
+parser!(UriParser, Uri, uri);
 
-// List of parsers, in order of precedence.
-const PARSERS: &[IdentifierParser] = &[
+// List of parsers in order of precedence.
+pub(crate) const PARSERS: &[&dyn Parser] = &[
     // DOIs are a subset of Handle, so must be attempted before Handles.
-    doi::try_parse,
-    orcid::try_parse,
-    isbn::try_parse,
-    ror::try_parse,
+    &DoiParser,
+    &OrcidParser,
+    &IsmnParser,
+    &IsbnParser,
+    &RorParser,
+    &ResearcherIdParser,
+    &ArkParser,
+    &SwhidParser,
+    &GridParser,
+    &OclcParser,
+    &GndParser,
+    &DimensionsParser,
+    &SemanticScholarParser,
+    &PurlParser,
+    &IsrcParser,
+    &IstcParser,
+    &AccessionParser,
+    &ShortDoiParser,
+    &IswcParser,
+    &PdbParser,
+    &UrnNbnParser,
     // URIs are greedy, so place last in the list.
-    uri::try_parse,
+    &UriParser,
 ];
 
+/// Find the host in a URI string, e.g. "example.com" in "https://user@example.com:8080/path".
+/// Returns None if there's no "scheme://" authority.
+fn uri_host(uri: &str) -> Option<&str> {
+    let (_, rest) = uri.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+
+    // Don't split IPv6 addresses, which are in brackets, on their colons.
+    let host = if host_port.starts_with('[') {
+        host_port.split_inclusive(']').next()?
+    } else {
+        host_port.split(':').next()?
+    };
+
+    Some(host).filter(|host| !host.is_empty())
+}
+
+/// A parser for an identifier scheme that isn't built in, e.g. an in-house repository ID.
+/// Used with [`Identifier::parse_with`].
+pub trait CustomParser {
+    /// The name of the scheme, e.g. "grant". It's used in the stable string, so should be stable too.
+    fn scheme(&self) -> &str;
+
+    /// Attempt to parse the input, returning the normalised value if it's recognised.
+    fn try_parse(&self, input: &str) -> Option<String>;
+}
+
+/// Encode a custom identifier as "scheme:value".
+/// A colon or percent sign in the scheme is percent-encoded, so the first colon always ends the scheme, and different
+/// identifiers can't have the same string.
+fn custom_stable_string(scheme: &str, value: &str) -> String {
+    let scheme = scheme.replace('%', "%25").replace(':', "%3A");
+
+    format!("{}:{}", scheme, value)
+}
+
+/// Decode a custom identifier from the string made by [`custom_stable_string`].
+/// Returns None if there's no colon to end the scheme.
+fn custom_from_stable_string(input: &str) -> Option<Identifier> {
+    let (scheme, value) = input.split_once(':')?;
+
+    // Every "%" in the encoded scheme starts "%25" or "%3A", so these can't overlap.
+    Some(Identifier::Custom {
+        scheme: scheme.replace("%3A", ":").replace("%25", "%"),
+        value: String::from(value),
+    })
+}
+
 impl Identifier {
     /// Parse an input string, producing an Identifier. This will always
     /// succeed, but if the type isn't recognised, an Identifier::String will be
     /// returned, which indicates that it wasn't possible to recognise it.
+    ///
+    /// Never panics, for any input. This is checked by the fuzz target in the "fuzz" directory.
     pub fn parse(input: &str) -> Identifier {
+        Identifier::parse_input(IdentifierParseInput::build(input))
+    }
+
+    /// Parse an owned input string, producing an Identifier.
+    /// Behaves identically to [`Identifier::parse`], but takes ownership of the input.
+    /// If the type isn't recognised, the input's allocation is reused for the Identifier::String.
+    pub fn parse_owned(input: String) -> Identifier {
+        Identifier::parse_input(IdentifierParseInput::build_owned(
+            input,
+            ParseOptions::default(),
+        ))
+    }
+
+    /// Parse each of a sequence of lines, such as from a newline-delimited file, producing an Identifier for each.
+    /// Lazy, so each line is only parsed as the result is consumed, and memory is flat however many lines there are.
+    /// Each line is parsed as [`Identifier::parse_owned`] would.
+    pub fn parse_lines<I: Iterator<Item = String>>(lines: I) -> impl Iterator<Item = Identifier> {
+        lines.map(Identifier::parse_owned)
+    }
+
+    /// Parse an input string, also trying the given custom parsers, producing an Identifier.
+    /// The custom parsers are tried in order, after all of the built-in types but before the fall-through URI and
+    /// String types. A match gives an [`Identifier::Custom`].
+    pub fn parse_with(input: &str, extra: &[&dyn CustomParser]) -> Identifier {
         let parse_input = IdentifierParseInput::build(input);
 
         for parser in PARSERS.iter() {
-            if let Some(result) = parser(&parse_input) {
+            // Try the custom parsers just before the greedy URI parser.
+            if parser.identifier_type() == IdentifierType::Uri {
+                if let Some(result) = extra.iter().find_map(|custom| {
+                    custom.try_parse(input).map(|value| Identifier::Custom {
+                        scheme: String::from(custom.scheme()),
+                        value,
+                    })
+                }) {
+                    return result;
+                }
+            }
+
+            if let Some(result) = parser.try_parse(&parse_input) {
                 return result;
             }
         }
 
         // Fall-back case.
-        Identifier::String(String::from(input))
+        Identifier::String(parse_input.raw.into_owned())
+    }
+
+    /// Parse an input from bytes, producing an Identifier.
+    /// Valid UTF-8 is parsed as [`Identifier::parse`] would. Invalid UTF-8 isn't rescued, as that could produce an
+    /// unintended identifier, so it always gives an Identifier::String of the lossy conversion, with invalid
+    /// sequences replaced by U+FFFD.
+    pub fn parse_bytes(input: &[u8]) -> Identifier {
+        match core::str::from_utf8(input) {
+            Ok(input) => Identifier::parse(input),
+            Err(_) => Identifier::String(String::from_utf8_lossy(input).into_owned()),
+        }
+    }
+
+    /// Parse an input string, also returning the type of the parser that matched.
+    /// Behaves identically to [`Identifier::parse`]. Useful for metrics on which types are found in a dataset.
+    pub fn parse_typed(input: &str) -> (Identifier, IdentifierType) {
+        Identifier::parse_input_typed(IdentifierParseInput::build(input))
+    }
+
+    /// List every type that the input could be parsed as, in order of precedence, before precedence picks one.
+    /// The first type is the one that [`Identifier::parse`] gives. Empty if the input would be an Identifier::String.
+    /// Useful for diagnostics, as it exposes overlaps between types, e.g. an ISBN that is also a valid relative URI.
+    pub fn candidate_types(input: &str) -> Vec<IdentifierType> {
+        let parse_input = IdentifierParseInput::build(input);
+
+        PARSERS
+            .iter()
+            .filter(|parser| parser.try_parse(&parse_input).is_some())
+            .map(|parser| parser.identifier_type())
+            .collect()
+    }
+
+    /// Parse an input string, also keeping the input exactly as supplied.
+    /// Behaves identically to [`Identifier::parse`]. Useful for audit trails, where the source's own form is needed
+    /// alongside the normalised Identifier.
+    pub fn parse_with_original(input: &str) -> ParsedIdentifier {
+        ParsedIdentifier {
+            identifier: Identifier::parse(input),
+            original: String::from(input),
+        }
+    }
+
+    /// Parse an input string with non-default options, producing an Identifier.
+    /// See [`ParseOptions`] for the available options.
+    pub fn parse_with_options(input: &str, options: &ParseOptions) -> Identifier {
+        Identifier::parse_input(IdentifierParseInput::new(
+            Cow::Borrowed(input),
+            options.clone(),
+        ))
+    }
+
+    /// Parse an input string strictly, producing an Identifier only if the type was recognised.
+    /// Unlike [`Identifier::parse`], an input that would fall through to Identifier::String is an error.
+    /// An ORCID iD or orcid.org URL with the wrong check digit is a [`ParseError::ChecksumMismatch`], rather than an
+    /// Identifier::String or Identifier::Uri. With the right check digit, both forms are an Identifier::Orcid.
+    pub fn parse_strict(input: &str) -> Result<Identifier, ParseError> {
+        Identifier::parse_owned_strict(String::from(input))
+    }
+
+    /// Construct a DOI from an already separated prefix (e.g. "10.5555") and suffix (e.g. "12345678").
+    /// The suffix is in native Unicode, not URL-encoded. It's lower-cased, as [`Identifier::parse`] would.
+    /// This skips the scheme and resolver handling of [`Identifier::parse`].
+    pub fn doi(prefix: &str, suffix: &str) -> Result<Identifier, ParseError> {
+        doi::from_parts(prefix, suffix).ok_or(ParseError::Invalid(IdentifierType::Doi))
+    }
+
+    /// Construct an ORCID from a bare ORCID iD, e.g. "0000-0002-1694-233X" or "0000 0002 1694 233X", checking the
+    /// check digit.
+    /// This skips the resolver handling of [`Identifier::parse`]. An iD with the right structure but the wrong check
+    /// digit is a [`ParseError::ChecksumMismatch`].
+    pub fn orcid(raw: &str) -> Result<Identifier, ParseError> {
+        orcid::from_raw(raw).ok_or_else(|| orcid_error(raw))
+    }
+
+    /// Construct an ISBN from an already normalised 13 digit ISBN, e.g. "9780306406157", checking the prefix and check
+    /// digit. This skips the separator handling and 10 digit conversion of [`Identifier::parse`].
+    pub fn isbn13(digits: &str) -> Result<Identifier, ParseError> {
+        isbn::from_13_digits(digits).ok_or(ParseError::Invalid(IdentifierType::Isbn))
+    }
+
+    fn parse_owned_strict(input: String) -> Result<Identifier, ParseError> {
+        match Identifier::parse_owned(input) {
+            // A bare ORCID iD or orcid.org URL with a bad check digit falls through to these types.
+            Identifier::String(value) | Identifier::Uri(value)
+                if orcid::is_checksum_mismatch(&value)
+                    || orcid::near_miss(&IdentifierParseInput::build(&value)).is_some() =>
+            {
+                Err(ParseError::ChecksumMismatch {
+                    kind: IdentifierType::Orcid,
+                })
+            }
+            Identifier::String(_) => Err(ParseError::Unrecognised),
+            result => Ok(result),
+        }
+    }
+
+    /// Try each parser in order of precedence, falling back to an Identifier::String.
+    fn parse_input(parse_input: IdentifierParseInput) -> Identifier {
+        Identifier::parse_input_typed(parse_input).0
+    }
+
+    /// Try each parser in order of precedence, returning the type of the parser that matched.
+    fn parse_input_typed(parse_input: IdentifierParseInput) -> (Identifier, IdentifierType) {
+        for parser in PARSERS.iter() {
+            if let Some(result) = parser.try_parse(&parse_input) {
+                return (result, parser.identifier_type());
+            }
+        }
+
+        // Fall-back case.
+        (
+            Identifier::String(parse_input.raw.into_owned()),
+            IdentifierType::String,
+        )
+    }
+
+    /// Parse an ORCID iD on the ORCID sandbox, e.g. "https://sandbox.orcid.org/0000-0002-1694-233X".
+    /// Returns the bare iD rather than an Identifier, as sandbox iDs must not be confused with production ones.
+    /// [`Identifier::parse`] doesn't recognise sandbox iDs as ORCIDs.
+    pub fn parse_orcid_sandbox(input: &str) -> Option<String> {
+        orcid::try_parse_sandbox(&IdentifierParseInput::build(input))
+    }
+
+    /// Find the identifiers embedded in free text, in the order they appear.
+    /// Recognises DOIs, ORCIDs, ROR URLs and ISBNs. This is conservative to avoid false positives in prose, e.g. DOIs
+    /// need the "10." prefix. Each candidate is validated in the same way as [`Identifier::parse`].
+    pub fn extract_all(text: &str) -> Vec<Identifier> {
+        extract::extract_all(text)
+    }
+
+    /// Find the single most valuable identifier in noisy text, such as an email signature or text copied from a PDF.
+    /// Identifiers are found as by [`Identifier::extract_all`], and DOIs are preferred, then ORCIDs, RORs and ISBNs.
+    /// Returns None if there are none.
+    pub fn best_match(text: &str) -> Option<Identifier> {
+        extract::best_match(text)
+    }
+
+    /// Are two input strings representations of the same identifier?
+    /// This is the canonical equality check across representations, e.g. a DOI as a URL and as a plain DOI, or an
+    /// ISBN in 10 and 13 digit forms. Both inputs are parsed and the results compared.
+    pub fn same_identifier(a: &str, b: &str) -> bool {
+        Identifier::parse(a) == Identifier::parse(b)
+    }
+
+    /// Remove duplicates from a list of identifiers, keeping the first of each in its original order.
+    /// As parsing normalises representations, this merges e.g. a DOI found as a URL and as a plain DOI, or an ISBN
+    /// found in 10 and 13 digit forms.
+    pub fn dedupe(items: Vec<Identifier>) -> Vec<Identifier> {
+        // Decide which to keep first, so the set can borrow the items rather than clone them.
+        let mut seen: BTreeSet<&Identifier> = BTreeSet::new();
+        let keep: Vec<bool> = items.iter().map(|item| seen.insert(item)).collect();
+
+        items
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(item, keep)| keep.then_some(item))
+            .collect()
+    }
+
+    /// Are two input strings the same ISBN, in either 10 or 13 digit form?
+    /// Like [`Identifier::same_identifier`], but false unless both inputs are valid ISBNs.
+    /// ISBN-10s are equivalent to ISBN-13s in the 978 range only. A 979 ISBN-13 has no 10 digit form, so it's never
+    /// equivalent to an ISBN-10, even if the other digits are the same.
+    pub fn isbn_equivalent(a: &str, b: &str) -> bool {
+        match (Identifier::parse(a), Identifier::parse(b)) {
+            (Identifier::Isbn(a), Identifier::Isbn(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Parse an input string, also recording which parsers were tried and why each rejected the input.
+    /// This is diagnostic only. The Identifier is the same as that returned by [`Identifier::parse`].
+    pub fn parse_with_trace(input: &str) -> (Identifier, ParseTrace) {
+        diagnostics::parse_with_trace(input)
+    }
+
+    /// Parse an input string and explain the result.
+    /// If the input wasn't recognised as a specific type, report whether it was nearly one, and why it wasn't.
+    pub fn diagnose(input: &str) -> Diagnosis {
+        diagnostics::diagnose(input)
+    }
+
+    /// Parse an input string and explain the result as JSON, for machine-readable diagnostics.
+    /// See [`Identifier::diagnose`].
+    #[cfg(feature = "serde")]
+    pub fn diagnose_json(input: &str) -> String {
+        // Serialising a struct of strings and numbers can't fail.
+        serde_json::to_string(&diagnostics::diagnose(input)).unwrap_or_default()
+    }
+
+    /// The type of this Identifier.
+    pub fn identifier_type(&self) -> IdentifierType {
+        match self {
+            Identifier::Doi {
+                prefix: _,
+                suffix: _,
+            } => IdentifierType::Doi,
+            Identifier::Orcid(_) => IdentifierType::Orcid,
+            Identifier::Ror(_) => IdentifierType::Ror,
+            Identifier::Uri(_) => IdentifierType::Uri,
+            Identifier::String(_) => IdentifierType::String,
+            Identifier::Isbn(_) => IdentifierType::Isbn,
+            Identifier::Ark { naan: _, name: _ } => IdentifierType::Ark,
+            Identifier::Ismn(_) => IdentifierType::Ismn,
+            Identifier::ResearcherId(_) => IdentifierType::ResearcherId,
+            Identifier::Swhid(_) => IdentifierType::Swhid,
+            Identifier::Grid(_) => IdentifierType::Grid,
+            Identifier::Oclc(_) => IdentifierType::Oclc,
+            Identifier::Gnd(_) => IdentifierType::Gnd,
+            Identifier::Dimensions(_) => IdentifierType::Dimensions,
+            Identifier::SemanticScholar(_) => IdentifierType::SemanticScholar,
+            Identifier::Purl(_) => IdentifierType::Purl,
+            Identifier::Isrc(_) => IdentifierType::Isrc,
+            Identifier::Custom {
+                scheme: _,
+                value: _,
+            } => IdentifierType::Custom,
+            Identifier::Istc(_) => IdentifierType::Istc,
+            Identifier::Accession(_) => IdentifierType::Accession,
+            Identifier::ShortDoi(_) => IdentifierType::ShortDoi,
+            Identifier::Iswc(_) => IdentifierType::Iswc,
+            Identifier::Pdb(_) => IdentifierType::Pdb,
+            Identifier::UrnNbn(_) => IdentifierType::UrnNbn,
+        }
+    }
+
+    /// Convert to a URI format, if possible, also trying to convert an Identifier::String that looks like a link, e.g.
+    /// "http://例え.jp/a b". This is best effort, as the conversion may not be what was meant, e.g. characters are
+    /// percent-encoded. Otherwise behaves identically to [`Identifier::to_uri`], which is conservative.
+    /// Only converts with the `std` feature.
+    pub fn try_to_uri_best_effort(&self) -> Option<String> {
+        match self {
+            Identifier::String(value) => uri::best_effort(value),
+            _ => self.to_uri(),
+        }
+    }
+
+    /// Convert to a URI format, as [`Identifier::to_uri`], but say why there isn't one.
+    /// Returns [`EncodeError::NoUri`] if the identifier has no URI representation, or [`EncodeError::Format`] if a DOI
+    /// suffix couldn't be encoded.
+    pub fn try_to_uri(&self) -> Result<String, EncodeError> {
+        match self {
+            Identifier::Doi {
+                prefix: _,
+                suffix: _,
+            } => doi::try_to_uri(self),
+            _ => self.to_uri().ok_or(EncodeError::NoUri),
+        }
     }
 
     /// Convert to a URI format, if possible.
@@ -108,9 +762,118 @@ impl Identifier {
 
             Identifier::Isbn(_) => isbn::to_uri(self),
             Identifier::Ror(_) => ror::to_uri(self),
+            Identifier::Ark { naan: _, name: _ } => ark::to_uri(self),
+            Identifier::Ismn(_) => ismn::to_uri(self),
+            Identifier::ResearcherId(_) => researcherid::to_uri(self),
+            Identifier::Swhid(_) => swhid::to_uri(self),
+            Identifier::Grid(_) => grid::to_uri(self),
+            Identifier::Oclc(_) => oclc::to_uri(self),
+            Identifier::Gnd(_) => gnd::to_uri(self),
+            Identifier::Dimensions(_) => dimensions::to_uri(self),
+            Identifier::SemanticScholar(_) => semanticscholar::to_uri(self),
+            Identifier::Purl(_) => purl::to_uri(self),
+            Identifier::Isrc(_) => isrc::to_uri(self),
+
+            // Custom schemes aren't known to have a URI.
+            Identifier::Custom {
+                scheme: _,
+                value: _,
+            } => None,
+            Identifier::Istc(_) => istc::to_uri(self),
+            Identifier::Accession(_) => accession::to_uri(self),
+            Identifier::ShortDoi(_) => shortdoi::to_uri(self),
+            Identifier::Iswc(_) => iswc::to_uri(self),
+            Identifier::Pdb(_) => pdb::to_uri(self),
+            Identifier::UrnNbn(_) => urn_nbn::to_uri(self),
+        }
+    }
+
+    /// The host that this identifier resolves on, e.g. "doi.org" for a DOI. This is the host of [`Identifier::to_uri`].
+    /// For URIs and PURLs it's the host of the URL, otherwise it's fixed for the type.
+    /// Returns None for types without a resolver, such as ISBN, and for URIs without a host, such as URNs.
+    pub fn resolver_host(&self) -> Option<&str> {
+        match self {
+            Identifier::Doi {
+                prefix: _,
+                suffix: _,
+            } => Some("doi.org"),
+            Identifier::Orcid(_) => Some("orcid.org"),
+            Identifier::Ror(_) => Some("ror.org"),
+            Identifier::Uri(value) | Identifier::Purl(value) => uri_host(value),
+            Identifier::Ark { naan: _, name: _ } => Some("n2t.net"),
+            Identifier::ResearcherId(_) => Some("www.webofscience.com"),
+            Identifier::Swhid(_) => Some("archive.softwareheritage.org"),
+            Identifier::Grid(_) => Some("www.grid.ac"),
+            Identifier::Oclc(_) => Some("www.worldcat.org"),
+            Identifier::Gnd(_) => Some("d-nb.info"),
+            Identifier::Dimensions(_) => Some("app.dimensions.ai"),
+            Identifier::SemanticScholar(value) if value.starts_with("CorpusID:") => {
+                Some("api.semanticscholar.org")
+            }
+            Identifier::SemanticScholar(_) => Some("www.semanticscholar.org"),
+            Identifier::Accession(_) => Some("www.ncbi.nlm.nih.gov"),
+            Identifier::ShortDoi(_) => Some("doi.org"),
+            Identifier::Pdb(_) => Some("www.rcsb.org"),
+            Identifier::UrnNbn(_) => Some("nbn-resolving.org"),
+            Identifier::String(_)
+            | Identifier::Isbn(_)
+            | Identifier::Ismn(_)
+            | Identifier::Isrc(_)
+            | Identifier::Istc(_)
+            | Identifier::Iswc(_)
+            | Identifier::Custom {
+                scheme: _,
+                value: _,
+            } => None,
+        }
+    }
+
+    /// Convert to an http or https URL for a web page about the identifier, if possible.
+    /// This is the same as [`Identifier::to_uri`], except where the URI isn't a web link. ISBNs link to WorldCat rather
+    /// than being a "urn:isbn:" URN, and URIs with other schemes return None.
+    pub fn to_web_url(&self) -> Option<String> {
+        match self {
+            Identifier::Isbn(_) => isbn::to_web_url(self),
+            _ => self
+                .to_uri()
+                .filter(|uri| uri.starts_with("https://") || uri.starts_with("http://")),
+        }
+    }
+
+    /// The BibTeX field name and value for the identifier, if it has one, e.g. ("doi", "10.5555/12345678").
+    /// DOIs are the bare DOI name and ISBNs are hyphenated, if their registration group is known (see
+    /// [`Identifier::isbn_parts`]), otherwise the 13 digits. Other types are a "url" field with the web URL (see
+    /// [`Identifier::to_web_url`]), so return None if they don't have one. Values aren't escaped for BibTeX.
+    pub fn to_bibtex_field(&self) -> Option<(&'static str, String)> {
+        match self {
+            Identifier::Doi { .. } => self.doi_name().map(|name| ("doi", name)),
+            Identifier::Isbn(_) => isbn::to_hyphenated(self).map(|isbn| ("isbn", isbn)),
+            _ => self.to_web_url().map(|url| ("url", url)),
+        }
+    }
+
+    /// Convert to a URN format, if possible.
+    /// DOIs are expressed in the "info:doi:" form and ISBNs in the "urn:isbn:" form. Other types return None.
+    pub fn to_urn(&self) -> Option<String> {
+        match self {
+            Identifier::Doi {
+                prefix: _,
+                suffix: _,
+            } => doi::to_urn(self),
+
+            // The URI for an ISBN is already a URN.
+            Identifier::Isbn(_) => isbn::to_uri(self),
+
+            _ => None,
         }
     }
 
+    /// Does this identifier have a URI representation?
+    /// True exactly when [`Identifier::to_uri`] returns a URI.
+    pub fn is_resolvable(&self) -> bool {
+        self.to_uri().is_some()
+    }
+
     /// Represent as a simple, stable string representation.
     /// Depending on type, this is sometimes the URI representation, sometimes not.
     /// This representation is meant to be stable and consistent, so that it can be used as a key in a database.
@@ -135,6 +898,24 @@ impl Identifier {
             // No natural URI for ISBN.
             Identifier::Isbn(_) => isbn::to_stable_string(self),
             Identifier::Ror(_) => ror::to_stable_string(self),
+            Identifier::Ark { naan: _, name: _ } => ark::to_stable_string(self),
+            Identifier::Ismn(_) => ismn::to_stable_string(self),
+            Identifier::ResearcherId(_) => researcherid::to_stable_string(self),
+            Identifier::Swhid(_) => swhid::to_stable_string(self),
+            Identifier::Grid(_) => grid::to_stable_string(self),
+            Identifier::Oclc(_) => oclc::to_stable_string(self),
+            Identifier::Gnd(_) => gnd::to_stable_string(self),
+            Identifier::Dimensions(_) => dimensions::to_stable_string(self),
+            Identifier::SemanticScholar(_) => semanticscholar::to_stable_string(self),
+            Identifier::Purl(_) => purl::to_stable_string(self),
+            Identifier::Isrc(_) => isrc::to_stable_string(self),
+            Identifier::Custom { scheme, value } => Some(custom_stable_string(scheme, value)),
+            Identifier::Istc(_) => istc::to_stable_string(self),
+            Identifier::Accession(_) => accession::to_stable_string(self),
+            Identifier::ShortDoi(_) => shortdoi::to_stable_string(self),
+            Identifier::Iswc(_) => iswc::to_stable_string(self),
+            Identifier::Pdb(_) => pdb::to_stable_string(self),
+            Identifier::UrnNbn(_) => urn_nbn::to_stable_string(self),
         };
 
         // All of the above should handle representation.
@@ -148,20 +929,101 @@ impl Identifier {
         }
     }
 
+    /// A compact key for comparison, e.g. for bloom filters and sharding.
+    /// This is a single character type tag and the normalised value, e.g. "d:10.5555/12345678" for a DOI. The tag
+    /// prevents collisions between types, e.g. a DOI suffix that looks like a ROR ID.
+    /// Two representations of the same identifier give the same key. Unlike [`Identifier::to_stable_string`], this
+    /// isn't meant to be parsed back, and the format may change between versions.
+    pub fn comparison_key(&self) -> String {
+        match self {
+            Identifier::Doi { prefix, suffix } => format!("d:{}/{}", prefix, suffix),
+            Identifier::Orcid(value) => format!("o:{}", value),
+            Identifier::Ror(value) => format!("r:{}", value),
+            Identifier::Isbn(value) => format!("i:{}", value),
+            Identifier::Uri(value) => format!("u:{}", value),
+            Identifier::String(value) => format!("s:{}", value),
+            Identifier::Ark { naan, name } => format!("a:{}/{}", naan, name),
+            Identifier::Ismn(value) => format!("m:{}", value),
+            Identifier::ResearcherId(value) => format!("w:{}", value),
+            Identifier::Swhid(value) => format!("h:{}", value),
+            Identifier::Grid(value) => format!("g:{}", value),
+            Identifier::Oclc(value) => format!("c:{}", value),
+            Identifier::Gnd(value) => format!("n:{}", value),
+            Identifier::Dimensions(value) => format!("x:{}", value),
+            Identifier::SemanticScholar(value) => format!("e:{}", value),
+            Identifier::Purl(value) => format!("p:{}", value),
+            Identifier::Isrc(value) => format!("k:{}", value),
+            Identifier::Custom { scheme, value } => {
+                format!("z:{}", custom_stable_string(scheme, value))
+            }
+            Identifier::Istc(value) => format!("t:{}", value),
+            Identifier::Accession(value) => format!("b:{}", value),
+            Identifier::ShortDoi(value) => format!("q:{}", value),
+            Identifier::Iswc(value) => format!("v:{}", value),
+            Identifier::Pdb(value) => format!("y:{}", value),
+            Identifier::UrnNbn(value) => format!("l:{}", value),
+        }
+    }
+
+    /// Normalise to the preferred display form.
+    /// This is the URI where there is one (DOI, ORCID, ROR, ARK, URI and `urn:isbn:` for ISBN), otherwise the stable string.
+    /// Unlike [`Identifier::to_stable_string`], which is meant for storage keys and is sometimes not the URI, this is meant for showing to people and linking.
+    pub fn normalise(&self) -> String {
+        self.to_uri().unwrap_or_else(|| self.to_stable_string())
+    }
+
+    /// Summarise the identifier, for display, e.g. by a command-line tool.
+    /// Gathers [`IdentifierType::name`], [`Identifier::normalise`], [`Identifier::to_uri`] and
+    /// [`Identifier::to_stable_string`] in one place.
+    pub fn describe(&self) -> IdentifierDescription {
+        IdentifierDescription {
+            type_name: self.identifier_type().name(),
+            normalised: self.normalise(),
+            uri: self.to_uri(),
+            stable_string: self.to_stable_string(),
+        }
+    }
+
     /// Convert to a pair of simple stable string representation and a numeric type id.
-    /// The simple string is usually not the URI format.
+    /// The string is as given by [`Identifier::to_stable_string`], which is the URI for some types, including DOIs.
     /// These type IDs are defined to be stable, and should not be altered.
     pub fn to_id_string_pair(&self) -> (String, u32) {
         let maybe_result = match self {
             Identifier::Doi {
                 prefix: _,
                 suffix: _,
-            } => (doi::to_stable_string(self), 1),
-            Identifier::Orcid(_) => (orcid::to_stable_string(self), 2),
-            Identifier::Ror(_) => (ror::to_stable_string(self), 3),
-            Identifier::Uri(_) => (uri::to_stable_string(self), 4),
-            Identifier::String(value) => (Some(value.clone()), 5),
-            Identifier::Isbn(_) => (isbn::to_stable_string(self), 6),
+            } => (doi::to_stable_string(self), TYPE_ID_DOI),
+            Identifier::Orcid(_) => (orcid::to_stable_string(self), TYPE_ID_ORCID),
+            Identifier::Ror(_) => (ror::to_stable_string(self), TYPE_ID_ROR),
+            Identifier::Uri(_) => (uri::to_stable_string(self), TYPE_ID_URI),
+            Identifier::String(value) => (Some(value.clone()), TYPE_ID_STRING),
+            Identifier::Isbn(_) => (isbn::to_stable_string(self), TYPE_ID_ISBN),
+            Identifier::Ark { naan: _, name: _ } => (ark::to_stable_string(self), TYPE_ID_ARK),
+            Identifier::Ismn(_) => (ismn::to_stable_string(self), TYPE_ID_ISMN),
+            Identifier::ResearcherId(_) => {
+                (researcherid::to_stable_string(self), TYPE_ID_RESEARCHERID)
+            }
+            Identifier::Swhid(_) => (swhid::to_stable_string(self), TYPE_ID_SWHID),
+            Identifier::Grid(_) => (grid::to_stable_string(self), TYPE_ID_GRID),
+            Identifier::Oclc(_) => (oclc::to_stable_string(self), TYPE_ID_OCLC),
+            Identifier::Gnd(_) => (gnd::to_stable_string(self), TYPE_ID_GND),
+            Identifier::Dimensions(_) => (dimensions::to_stable_string(self), TYPE_ID_DIMENSIONS),
+            Identifier::SemanticScholar(_) => (
+                semanticscholar::to_stable_string(self),
+                TYPE_ID_SEMANTICSCHOLAR,
+            ),
+            Identifier::Purl(_) => (purl::to_stable_string(self), TYPE_ID_PURL),
+            Identifier::Isrc(_) => (isrc::to_stable_string(self), TYPE_ID_ISRC),
+            Identifier::Custom {
+                scheme: _,
+                value: _,
+            } => (Some(self.to_stable_string()), TYPE_ID_CUSTOM),
+            Identifier::Istc(_) => (istc::to_stable_string(self), TYPE_ID_ISTC),
+            Identifier::Accession(_) => (accession::to_stable_string(self), TYPE_ID_ACCESSION),
+            Identifier::ShortDoi(_) => (shortdoi::to_stable_string(self), TYPE_ID_SHORTDOI),
+            Identifier::Iswc(_) => (iswc::to_stable_string(self), TYPE_ID_ISWC),
+            Identifier::Pdb(_) => (pdb::to_stable_string(self), TYPE_ID_PDB),
+            Identifier::UrnNbn(_) => (urn_nbn::to_stable_string(self), TYPE_ID_URNNBN),
         };
 
         // All of the above should handle representations.
@@ -170,119 +1032,561 @@ impl Identifier {
             (Some(value), type_id) => (value, type_id),
             _ => {
                 log::error!("Failed to convert to string: {:?}", self);
-                (format!("{:?}", self), 5)
+                (format!("{:?}", self), TYPE_ID_STRING)
             }
         }
     }
 
-    /// Construct from a (type id, string) pair.
-    pub fn from_id_string_pair(input_str: &str, type_id: u32) -> Option<Identifier> {
-        let parse_input = IdentifierParseInput::build(input_str);
+    /// Is this a DOI whose suffix contains a UUID?
+    /// Useful for distinguishing auto-minted DOIs (common at DataCite) from human-readable ones.
+    pub fn is_uuid_suffix_doi(&self) -> bool {
+        doi::is_uuid_suffix(self)
+    }
 
-        match type_id {
-            1 => doi::try_parse(&parse_input),
-            2 => orcid::try_parse(&parse_input),
-            3 => ror::try_parse(&parse_input),
-            4 => uri::try_parse(&parse_input),
-            5 => Some(Identifier::String(String::from(input_str))),
-            6 => isbn::try_parse(&parse_input),
-            _ => {
-                log::error!("Unrecognised type id {}", type_id);
-                None
-            }
-        }
+    /// Is this a DOI under a prefix reserved for testing and examples, such as "10.5555"?
+    /// Useful for filtering example DOIs out of production metadata.
+    pub fn is_test_doi(&self) -> bool {
+        doi::is_test(self)
     }
-}
 
-/// Intermediary representation of an input with pre-computed values needed by various parsers.
-#[derive(Debug)]
-pub(crate) struct IdentifierParseInput {
-    pub raw: String,
+    /// Is this a RAiD, a Research Activity Identifier for a research project?
+    /// RAiDs are DOIs under dedicated prefixes, so they also parse, resolve and compare as DOIs.
+    pub fn is_raid(&self) -> bool {
+        doi::is_raid(self)
+    }
 
-    pub uri: Option<Uri>,
-}
+    /// Encode a RAiD as a URI on raid.org, e.g. "https://raid.org/10.80368/b1adfb3a".
+    /// Returns None for anything that isn't a RAiD. Use `to_uri` for the doi.org form.
+    pub fn to_raid_uri(&self) -> Option<String> {
+        doi::to_raid_uri(self)
+    }
 
-impl IdentifierParseInput {
-    fn build(input: &str) -> IdentifierParseInput {
-        // Nearly all identifier types want the input parsed to a URI.
-        let valid_uri = match Uri::from_str(input) {
-            Ok(result) => Some(result),
-            Err(_) => None,
-        };
+    /// Is this an IGSN, an identifier for a physical sample?
+    /// IGSNs are DOIs under dedicated prefixes, so they also parse, resolve and compare as DOIs.
+    pub fn is_igsn(&self) -> bool {
+        doi::is_igsn(self)
+    }
 
-        IdentifierParseInput {
-            raw: String::from(input),
-            uri: valid_uri,
-        }
+    /// Encode an IGSN as a URI on igsn.org, e.g. "https://igsn.org/10.58052/ieuhb0002".
+    /// Returns None for anything that isn't an IGSN. Use `to_uri` for the doi.org form.
+    pub fn to_igsn_uri(&self) -> Option<String> {
+        doi::to_igsn_uri(self)
     }
 
-    /// Return the path with the leading slash removed.
-    /// There may not be a leading slash.
-    pub(crate) fn path_no_slash(&self) -> Option<String> {
-        match self.uri {
-            Some(ref uri) => {
-                let path = uri.path();
-
-                Some(String::from(if let Some(rest) = path.strip_prefix("/") {
-                    rest
-                } else {
-                    path
-                }))
-            }
+    /// The DOI name, i.e. "prefix/suffix" in native Unicode, not URL-encoded.
+    /// This is the form for display and for the "doi:" scheme. Returns None for any non-DOI type.
+    pub fn doi_name(&self) -> Option<String> {
+        doi::to_name(self)
+    }
+
+    /// The prefix and suffix of a DOI, borrowed rather than copied, e.g. ("10.5555", "12345678").
+    /// Returns None for any non-DOI type.
+    pub fn doi_parts(&self) -> Option<(&str, &str)> {
+        match self {
+            Identifier::Doi { prefix, suffix } => Some((prefix, suffix)),
             _ => None,
         }
     }
 
-    pub(crate) fn path_no_slash_uppercase(&self) -> Option<String> {
-        self.path_no_slash().map(|path| path.to_uppercase())
+    /// The Registration Agency of a DOI, such as Crossref or DataCite, from a bundled table of well-known prefixes.
+    /// Returns None if this isn't a DOI, or if the prefix isn't in the table. See the `agency` feature.
+    #[cfg(feature = "agency")]
+    pub fn doi_registration_agency(&self) -> Option<Agency> {
+        agency::registration_agency(self)
     }
 
-    pub(crate) fn host(&self) -> Option<&str> {
-        match &self.uri {
-            Some(uri) => uri.host(),
-            _ => None,
-        }
+    /// Is this a Crossref Funder ID, i.e. a DOI under the "10.13039" prefix?
+    /// Funder IDs resolve in the Open Funder Registry, and identify funders rather than content.
+    pub fn is_funder_doi(&self) -> bool {
+        doi::is_funder(self)
     }
 
-    pub(crate) fn host_lowercase(&self) -> Option<String> {
-        self.host().map(|x| x.to_lowercase())
+    /// If this is an ISBN-A, a DOI expressing an ISBN under a "10.978." or "10.979." prefix, return the ISBN.
+    /// Returns None for any other identifier, or if the ISBN's check digit is wrong.
+    pub fn as_isbn_from_isbn_a(&self) -> Option<Identifier> {
+        isbn::from_isbn_a(self)
     }
-}
 
-// End-to-end tests for each type.
-#[cfg(test)]
-mod doi_end_to_end_tests {
-    use super::*;
+    /// The elements of an ISBN, from a bundled table of registrant ranges.
+    /// Returns None for any non-ISBN type, or if the ISBN's registration group isn't in the table. The table only covers
+    /// registration groups 978-0 and 978-1 (English language) and 978-4 (Japanese language), from the International ISBN
+    /// Agency's range message, so ISBNs from all other groups give None.
+    pub fn isbn_parts(&self) -> Option<IsbnParts> {
+        isbn::parts(self)
+    }
 
-    #[test]
-    fn stable() {
-        let inputs = vec![
-            // DOI
-            "doi.org/10.5555/12345678",
-            "https://doi.org/10.5555/12345678",
-            "10.5555/12345678",
-            "10.5555/1234e®🄮™5678",
-            // ISBN
-            "0306406152",
-            // ORCID
-            "https://orcid.org/0000-0002-1694-233X",
-            // ROR
-            "https://ror.org/02twcfp32",
-            // URI
-            "https://example.com",
-            // String
-            "hello",
-        ];
+    /// Could this and the other identifier be aliases, i.e. the same thing expressed as different types?
+    /// Identifiers of the same type are aliases only if they're equal. The cross-type relationship known is an ISBN-A
+    /// DOI and its ISBN, in either order. There's no GRID to ROR mapping, so those are never aliases.
+    pub fn is_alias_of(&self, other: &Identifier) -> bool {
+        match (self, other) {
+            (Identifier::Doi { .. }, Identifier::Isbn(_)) => {
+                self.as_isbn_from_isbn_a().as_ref() == Some(other)
+            }
+            (Identifier::Isbn(_), Identifier::Doi { .. }) => other.is_alias_of(self),
+            _ => self == other,
+        }
+    }
 
-        for input in inputs.iter() {
-            let parsed = Identifier::parse(input);
+    /// Construct from a (type id, string) pair.
+    /// Returns None if the string isn't a valid identifier of the given type. Custom identifiers are rebuilt from their
+    /// "scheme:value" string, without their parser.
+    pub fn from_id_string_pair(input_str: &str, type_id: u32) -> Option<Identifier> {
+        let parse_input = IdentifierParseInput::build(input_str);
 
-            let as_string = parsed.to_stable_string();
+        let result = if type_id == TYPE_ID_STRING {
+            Some(Identifier::String(String::from(input_str)))
+        } else if type_id == TYPE_ID_CUSTOM {
+            // Custom identifiers aren't recognised by any parser here, but the string is enough to rebuild them.
+            custom_from_stable_string(input_str)
+        } else if let Some(parser) = PARSERS.iter().find(|parser| parser.type_id() == type_id) {
+            parser.try_parse(&parse_input)
+        } else {
+            log::error!("Unrecognised type id {}", type_id);
+            None
+        };
 
-            // Not all types produce a URI.
-            let as_uri = parsed.to_uri();
+        // Guard that the result really is of the requested type, so the pair round-trip can be trusted.
+        result.filter(|identifier| identifier.identifier_type().id() == type_id)
+    }
+}
 
-            let (id_value, id_type) = parsed.to_id_string_pair();
+/// Identifiers are ordered first by their stable type id, then by their stable string within a type.
+/// This groups identifiers by kind, and is deterministic. Custom identifiers are ordered by scheme, then value, so that
+/// identifiers are only equal in order if they're equal.
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                Identifier::Custom { scheme, value },
+                Identifier::Custom {
+                    scheme: other_scheme,
+                    value: other_value,
+                },
+            ) => (scheme, value).cmp(&(other_scheme, other_value)),
+            _ => self
+                .identifier_type()
+                .id()
+                .cmp(&other.identifier_type().id())
+                .then_with(|| self.to_stable_string().cmp(&other.to_stable_string())),
+        }
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl TryFrom<&str> for Identifier {
+    type Error = ParseError;
+
+    /// Parse strictly. See [`Identifier::parse_strict`].
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Identifier::parse_strict(input)
+    }
+}
+
+impl TryFrom<String> for Identifier {
+    type Error = ParseError;
+
+    /// Parse strictly. See [`Identifier::parse_strict`].
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Identifier::parse_owned_strict(input)
+    }
+}
+
+impl From<&Identifier> for (String, u32) {
+    /// See [`Identifier::to_id_string_pair`].
+    fn from(identifier: &Identifier) -> Self {
+        identifier.to_id_string_pair()
+    }
+}
+
+impl TryFrom<(String, u32)> for Identifier {
+    type Error = ParseError;
+
+    /// See [`Identifier::from_id_string_pair`].
+    /// Unlike that method, distinguishes an unknown type id from a value that isn't valid for its type.
+    fn try_from((value, type_id): (String, u32)) -> Result<Self, Self::Error> {
+        let identifier_type = IdentifierType::all()
+            .iter()
+            .find(|identifier_type| identifier_type.id() == type_id)
+            .ok_or(ParseError::UnknownTypeId(type_id))?;
+
+        Identifier::from_id_string_pair(&value, type_id)
+            .ok_or(ParseError::Invalid(*identifier_type))
+    }
+}
+
+/// An Identifier with the input it was parsed from, as returned by [`Identifier::parse_with_original`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsedIdentifier {
+    /// The parsed Identifier.
+    pub identifier: Identifier,
+
+    /// The input exactly as supplied, before any normalisation.
+    pub original: String,
+}
+
+/// The elements of an ISBN, as returned by [`Identifier::isbn_parts`], e.g. "978", "0", "306", "40615" and "7" for
+/// ISBN 978-0-306-40615-7.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IsbnParts {
+    /// The EAN prefix, "978" or "979".
+    pub prefix: String,
+
+    /// The registration group, which is a language area or country.
+    pub registration_group: String,
+
+    /// The registrant, usually a publisher.
+    pub registrant: String,
+
+    /// The publication, assigned by the registrant.
+    pub publication: String,
+
+    /// The check digit.
+    pub check_digit: String,
+}
+
+/// A summary of an Identifier, as returned by [`Identifier::describe`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IdentifierDescription {
+    /// The name of the type, as given by [`IdentifierType::name`].
+    pub type_name: &'static str,
+
+    /// The preferred display form, as given by [`Identifier::normalise`].
+    pub normalised: String,
+
+    /// The URI, if the identifier has one.
+    pub uri: Option<String>,
+
+    /// The stable string, as given by [`Identifier::to_stable_string`].
+    pub stable_string: String,
+}
+
+/// Reasons that an input couldn't be parsed as an Identifier.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    /// The input wasn't recognised as any type of identifier.
+    Unrecognised,
+
+    /// The components supplied to a constructor such as [`Identifier::doi`] aren't valid for the type.
+    Invalid(IdentifierType),
+
+    /// The type id of a (string, type id) pair isn't one of the stable type ids.
+    UnknownTypeId(u32),
+
+    /// The input has the structure of the type, but the wrong check digit, e.g. ORCID iD "0000-0002-1694-2330".
+    /// This is nearly an identifier, and was probably mistyped.
+    ChecksumMismatch { kind: IdentifierType },
+}
+
+/// The error for a bare ORCID iD that isn't valid.
+fn orcid_error(raw: &str) -> ParseError {
+    if orcid::is_checksum_mismatch(raw) {
+        ParseError::ChecksumMismatch {
+            kind: IdentifierType::Orcid,
+        }
+    } else {
+        ParseError::Invalid(IdentifierType::Orcid)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Unrecognised => write!(f, "unrecognised identifier"),
+            ParseError::Invalid(identifier_type) => {
+                write!(f, "invalid {:?} components", identifier_type)
+            }
+            ParseError::UnknownTypeId(type_id) => write!(f, "unknown type id {}", type_id),
+            ParseError::ChecksumMismatch { kind } => write!(f, "bad {:?} check digit", kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Options that alter how inputs are parsed.
+/// The default options are used by [`Identifier::parse`], so [`Identifier::parse_with_options`] with the default
+/// options behaves identically.
+///
+/// Parsers never change the input seen by other parsers. Each compares against its own case-converted copy, and the
+/// value it stores follows the type's case policy:
+///  - DOI suffixes and shortDOIs are lower-cased, as DOIs are case-insensitive. See `preserve_doi_case`.
+///  - ORCID check digits, ISBN, ISMN, ISRC, ISTC, ISWC, GND and ResearcherID values are upper-cased.
+///  - GRID IDs and Semantic Scholar paper IDs are lower-cased. ROR IDs are only recognised in lower case.
+///  - ARK names, PURL paths, accessions and URI paths and queries are case-sensitive, so are kept as they are. URI
+///    and PURL hosts are lower-cased.
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    /// Reject ISBNs with more hyphens or spaces than a real ISBN can have.
+    /// A 10-digit ISBN has at most 3 separators, a 13-digit ISBN at most 4.
+    pub strict_isbn_separators: bool,
+
+    /// Keep the case of DOI suffixes rather than lower-casing them.
+    /// DOIs are officially case-insensitive, and lower-casing allows them to be compared for equality. Some systems
+    /// treat suffixes as case-sensitive, so this avoids changing their values, but DOIs that differ only in case will
+    /// no longer be equal.
+    pub preserve_doi_case: bool,
+
+    /// Remove punctuation from the end of a DOI, e.g. the full stop in "10.5555/12345678.", which is common when DOIs
+    /// are copied from the end of a sentence. This is ambiguous, as some DOIs really do end in punctuation, so these
+    /// DOIs will be changed. Punctuation is removed in the same way as [`Identifier::extract_all`].
+    pub trim_doi_trailing_punctuation: bool,
+
+    /// Recognise ORCID iDs on the ORCID sandbox, e.g. "https://sandbox.orcid.org/0000-0002-1694-233X", as ORCIDs.
+    /// Sandbox iDs aren't real ORCID iDs, and the resulting Identifier is indistinguishable from a production one,
+    /// e.g. its URI is on orcid.org. Only use this where sandbox and production iDs can't be mixed, e.g. in testing.
+    /// Otherwise see [`Identifier::parse_orcid_sandbox`].
+    pub sandbox_orcid: bool,
+}
+
+/// Intermediary representation of an input with values needed by various parsers.
+/// The input is borrowed where possible, and derived values are only allocated when a parser needs them.
+#[derive(Debug)]
+pub(crate) struct IdentifierParseInput<'a> {
+    pub raw: Cow<'a, str>,
+
+    /// Parsed on first use, as not every parser needs it.
+    #[cfg(feature = "std")]
+    uri: OnceCell<Option<Uri>>,
+
+    pub options: ParseOptions,
+}
+
+impl<'a> IdentifierParseInput<'a> {
+    pub(crate) fn build(input: &'a str) -> IdentifierParseInput<'a> {
+        IdentifierParseInput::new(Cow::Borrowed(input), ParseOptions::default())
+    }
+
+    fn build_owned(input: String, options: ParseOptions) -> IdentifierParseInput<'static> {
+        IdentifierParseInput::new(Cow::Owned(input), options)
+    }
+
+    fn new(input: Cow<'a, str>, options: ParseOptions) -> IdentifierParseInput<'a> {
+        IdentifierParseInput {
+            options,
+            #[cfg(feature = "std")]
+            uri: OnceCell::new(),
+            raw: input,
+        }
+    }
+
+    /// Build an input from a different string, with the same options.
+    pub(crate) fn with_raw<'b>(&self, raw: &'b str) -> IdentifierParseInput<'b> {
+        IdentifierParseInput::new(Cow::Borrowed(raw), self.options.clone())
+    }
+
+    /// Return the input parsed as a URI, if it is one.
+    #[cfg(feature = "std")]
+    pub(crate) fn uri(&self) -> Option<&Uri> {
+        self.uri
+            .get_or_init(|| {
+                // Nearly all identifier types want the input parsed to a URI.
+                // If it's not a valid URI, it may be a valid IRI.
+                Uri::from_str(&self.raw).ok().or_else(|| {
+                    uri::iri_to_uri(&self.raw).and_then(|converted| Uri::from_str(&converted).ok())
+                })
+            })
+            .as_ref()
+    }
+
+    /// Return the raw input in lower case. Only allocates if the input isn't already lower case.
+    pub(crate) fn raw_lowercase(&self) -> Cow<'_, str> {
+        if self
+            .raw
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(self.raw.chars())
+        {
+            Cow::Borrowed(&self.raw)
+        } else {
+            Cow::Owned(self.raw.to_lowercase())
+        }
+    }
+
+    /// Return the raw input in upper case. Only allocates if the input isn't already upper case.
+    pub(crate) fn raw_uppercase(&self) -> Cow<'_, str> {
+        if self
+            .raw
+            .chars()
+            .flat_map(char::to_uppercase)
+            .eq(self.raw.chars())
+        {
+            Cow::Borrowed(&self.raw)
+        } else {
+            Cow::Owned(self.raw.to_uppercase())
+        }
+    }
+
+    /// Return the path, which may have a leading slash.
+    #[cfg(feature = "std")]
+    fn path(&self) -> Option<&str> {
+        self.uri().map(|uri| uri.path())
+    }
+
+    /// Return the path, which may have a leading slash.
+    /// Without std there's no URI parser, so find the path after the "scheme://host" of the raw input.
+    #[cfg(not(feature = "std"))]
+    fn path(&self) -> Option<&str> {
+        self.split_authority().map(|(_, path)| path)
+    }
+
+    /// Return the query string, without the leading "?".
+    #[cfg(feature = "std")]
+    pub(crate) fn query(&self) -> Option<&str> {
+        self.uri().and_then(|uri| uri.query())
+    }
+
+    /// Return the query string, without the leading "?".
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn query(&self) -> Option<&str> {
+        let (_, rest) = self.raw.split_once('?')?;
+        rest.split('#').next()
+    }
+
+    /// Split the raw input into host and path, ignoring any query or fragment.
+    /// Only recognises inputs with a "scheme://" prefix, which is sufficient for the resolver URLs that need a host.
+    /// Any userinfo and numeric port are dropped from the host, as the URI parser does with std.
+    #[cfg(not(feature = "std"))]
+    fn split_authority(&self) -> Option<(&str, &str)> {
+        let (_, rest) = self.raw.split_once("://")?;
+        let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, ""),
+        };
+
+        let host_port = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+
+        // An IPv6 address without a port ends in "]", so its colons are never followed only by digits.
+        let host = match host_port.rsplit_once(':') {
+            Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+            _ => host_port,
+        };
+
+        Some((host, path))
+    }
+
+    /// Return the path with the leading slash removed.
+    /// There may not be a leading slash.
+    pub(crate) fn path_no_slash(&self) -> Option<String> {
+        self.path()
+            .map(|path| String::from(path.strip_prefix("/").unwrap_or(path)))
+    }
+
+    /// Return the path of an input without a scheme that starts with the given host, e.g. "ror.org/02twcfp32".
+    /// Without a scheme the URI parser doesn't find a host, so this works on the raw input.
+    pub(crate) fn schemeless_path(&self, host: &str) -> Option<&str> {
+        let (candidate, path) = self.raw.split_once('/')?;
+
+        if candidate.eq_ignore_ascii_case(host) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn path_no_slash_uppercase(&self) -> Option<String> {
+        self.path_no_slash().map(|path| path.to_uppercase())
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn host(&self) -> Option<&str> {
+        self.uri().and_then(|uri| uri.host())
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn host(&self) -> Option<&str> {
+        self.split_authority()
+            .map(|(host, _)| host)
+            .filter(|host| !host.is_empty())
+    }
+
+    pub(crate) fn host_lowercase(&self) -> Option<String> {
+        self.host().map(|x| x.to_lowercase())
+    }
+}
+
+// End-to-end tests for each type.
+#[cfg(test)]
+mod doi_end_to_end_tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn stable() {
+        let inputs = vec![
+            // DOI
+            "doi.org/10.5555/12345678",
+            "https://doi.org/10.5555/12345678",
+            "10.5555/12345678",
+            "10.5555/1234e®🄮™5678",
+            // ISBN
+            "0306406152",
+            // ORCID
+            "https://orcid.org/0000-0002-1694-233X",
+            // ROR
+            "https://ror.org/02twcfp32",
+            // ARK
+            "ark:/12345/x6np1wh8k",
+            // ARK
+            "https://n2t.net/ark:/13030/tf5p30086k",
+            // ISMN
+            "M-2600-0043-8",
+            // ResearcherID
+            "A-1234-2010",
+            // SWHID
+            "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+            // GRID
+            "grid.5072.0",
+            // OCLC
+            "ocm12345678",
+            // GND
+            "https://d-nb.info/gnd/118540238",
+            // Dimensions
+            "pub.1234567890",
+            // Semantic Scholar
+            "CorpusID:12345678",
+            // PURL
+            "https://purl.org/dc/terms/title",
+            // ISRC
+            "US-RC1-17-00274",
+            // ISTC
+            "0A9-2002-12B4A105-7",
+            // Accession
+            "AB123456.1",
+            // shortDOI
+            "10/abcde",
+            // ISWC
+            "T0345246801",
+            // PDB
+            "pdb_10001abc",
+            // URN:NBN
+            "URN:NBN:de:bsz:93-opus-59016",
+            // URI
+            "https://example.com",
+            // String
+            "hello",
+        ];
+
+        for input in inputs.iter() {
+            let parsed = Identifier::parse(input);
+
+            let as_string = parsed.to_stable_string();
+
+            // Not all types produce a URI.
+            let as_uri = parsed.to_uri();
+
+            let (id_value, id_type) = parsed.to_id_string_pair();
 
             let as_string_parsed = Identifier::parse(&as_string);
             assert_eq!(
@@ -309,3 +1613,1495 @@ mod doi_end_to_end_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod parse_owned_tests {
+    use super::*;
+
+    #[test]
+    fn matches_parse() {
+        let inputs = [
+            // DOI
+            "https://doi.org/10.5555/12345678",
+            // ISBN
+            "0306406152",
+            // ORCID
+            "https://orcid.org/0000-0002-1694-233X",
+            // ROR
+            "https://ror.org/02twcfp32",
+            // URI
+            "https://example.com",
+            // String
+            "hello world",
+        ];
+
+        for input in inputs.iter() {
+            assert_eq!(
+                Identifier::parse(input),
+                Identifier::parse_owned(String::from(*input)),
+                "Expected parse_owned to match parse for {}",
+                input
+            );
+        }
+    }
+
+    /// The fall-through case keeps the input as-is.
+    #[test]
+    fn string_fall_through() {
+        assert_eq!(
+            Identifier::String(String::from("hello world")),
+            Identifier::parse_owned(String::from("hello world"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod ordering_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sort_by_type() {
+        let mut identifiers = [
+            Identifier::parse("0306406152"),
+            Identifier::parse("hello world"),
+            Identifier::parse("https://example.com"),
+            Identifier::parse("https://ror.org/02twcfp32"),
+            Identifier::parse("https://orcid.org/0000-0002-1694-233X"),
+            Identifier::parse("10.5555/12345678"),
+        ];
+
+        identifiers.sort();
+
+        let types: Vec<IdentifierType> = identifiers.iter().map(|x| x.identifier_type()).collect();
+
+        assert_eq!(
+            types,
+            [
+                IdentifierType::Doi,
+                IdentifierType::Orcid,
+                IdentifierType::Ror,
+                IdentifierType::Uri,
+                IdentifierType::String,
+                IdentifierType::Isbn,
+            ],
+            "Identifiers should be sorted in type id order."
+        );
+    }
+
+    #[test]
+    fn sort_within_type() {
+        let mut identifiers = [
+            Identifier::parse("10.5555/bbb"),
+            Identifier::parse("https://orcid.org/0000-0002-1694-233X"),
+            Identifier::parse("10.5555/aaa"),
+        ];
+
+        identifiers.sort();
+
+        assert_eq!(
+            identifiers,
+            [
+                Identifier::parse("10.5555/aaa"),
+                Identifier::parse("10.5555/bbb"),
+                Identifier::parse("https://orcid.org/0000-0002-1694-233X"),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod dedupe_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn merges_representations() {
+        let items = [
+            "https://doi.org/10.5555/ABC",
+            "0306406152",
+            "https://example.com/path",
+            "10.5555/abc",
+            "978-0-306-40615-7",
+            "https://example.com/path",
+        ]
+        .iter()
+        .map(|input| Identifier::parse(input))
+        .collect();
+
+        assert_eq!(
+            Identifier::dedupe(items),
+            [
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from("abc")
+                },
+                Identifier::Isbn(String::from("9780306406157")),
+                Identifier::Uri(String::from("https://example.com/path")),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Identifier::dedupe(Vec::new()), []);
+    }
+
+    #[test]
+    fn custom() {
+        let a = Identifier::Custom {
+            scheme: String::from("a:b"),
+            value: String::from("c"),
+        };
+        let b = Identifier::Custom {
+            scheme: String::from("a"),
+            value: String::from("b:c"),
+        };
+
+        assert_eq!(
+            Identifier::dedupe(alloc::vec![a.clone(), b.clone(), a.clone()]),
+            [a, b],
+            "Custom identifiers with the same stable string parts are still different."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        let set: std::collections::HashSet<Identifier> = [
+            Identifier::parse("10.5555/ABC"),
+            Identifier::parse("https://doi.org/10.5555/abc"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.len(), 1, "Equal identifiers should hash the same.");
+    }
+}
+
+#[cfg(test)]
+mod same_identifier_tests {
+    use super::*;
+
+    #[test]
+    fn doi() {
+        assert!(Identifier::same_identifier(
+            "10.5555/ABC",
+            "https://doi.org/10.5555/abc"
+        ));
+
+        assert!(
+            Identifier::same_identifier("10.5555/<>", "https://dx.doi.org/10.5555/%3C%3E"),
+            "Encoded and unencoded DOIs should be the same."
+        );
+
+        assert!(!Identifier::same_identifier("10.5555/abc", "10.5555/abd"));
+    }
+
+    #[test]
+    fn orcid() {
+        assert!(Identifier::same_identifier(
+            "https://orcid.org/0000-0002-1694-233x",
+            "HTTP://ORCID.ORG/0000-0002-1694-233X"
+        ));
+    }
+
+    #[test]
+    fn isbn() {
+        assert!(Identifier::same_identifier(
+            "0306406152",
+            "978-0-306-40615-7"
+        ));
+
+        assert!(!Identifier::same_identifier("0306406152", "9781566199094"));
+    }
+}
+
+#[cfg(test)]
+mod strict_tests {
+    use super::*;
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            Identifier::try_from("10.5555/12345678"),
+            Ok(Identifier::parse("10.5555/12345678"))
+        );
+
+        let result: Result<Identifier, ParseError> =
+            "https://orcid.org/0000-0002-1694-233X".try_into();
+        assert_eq!(
+            result,
+            Ok(Identifier::Orcid(String::from("0000-0002-1694-233X")))
+        );
+    }
+
+    #[test]
+    fn try_from_string() {
+        assert_eq!(
+            Identifier::try_from(String::from("0306406152")),
+            Ok(Identifier::Isbn(String::from("9780306406157")))
+        );
+    }
+
+    #[test]
+    fn unrecognised() {
+        assert_eq!(
+            Identifier::try_from("hello world"),
+            Err(ParseError::Unrecognised)
+        );
+
+        assert_eq!(
+            Identifier::try_from(String::from("hello world")),
+            Err(ParseError::Unrecognised)
+        );
+
+        assert_eq!(
+            Identifier::parse_strict("hello world"),
+            Err(ParseError::Unrecognised)
+        );
+    }
+}
+
+#[cfg(test)]
+mod resolver_host_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resolver_hosts() {
+        let examples = [
+            ("10.5555/12345678", Some("doi.org")),
+            ("https://orcid.org/0000-0002-1694-233X", Some("orcid.org")),
+            ("https://ror.org/02twcfp32", Some("ror.org")),
+            ("https://example.com/path", Some("example.com")),
+            ("http://user@example.com:8080/path", Some("example.com")),
+            ("urn:uuid:6e8bc430-9c3a-11d9-9669-0800200c9a66", None),
+            ("hello world", None),
+            ("0306406152", None),
+            ("ark:/12345/x6np1wh8k", Some("n2t.net")),
+            ("979-0-2600-0043-8", None),
+            ("A-1234-2010", Some("www.webofscience.com")),
+            (
+                "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+                Some("archive.softwareheritage.org"),
+            ),
+            ("grid.5072.0", Some("www.grid.ac")),
+            ("(OCoLC)12345678", Some("www.worldcat.org")),
+            ("(DE-588)118540238", Some("d-nb.info")),
+            ("pub.1234567890", Some("app.dimensions.ai")),
+            ("CorpusID:12345678", Some("api.semanticscholar.org")),
+            (
+                "649def34f8be52c8b66281af98ae884c09aef38b",
+                Some("www.semanticscholar.org"),
+            ),
+            ("http://purl.org/dc/terms/title", Some("purl.org")),
+            ("US-RC1-17-00274", None),
+            ("0A9-2002-12B4A105-7", None),
+            ("NM_001301717", Some("www.ncbi.nlm.nih.gov")),
+            ("10/abcde", Some("doi.org")),
+            ("T-034.524.680-1", None),
+            ("1ABC", Some("www.rcsb.org")),
+            ("urn:nbn:fi-fe20031153", Some("nbn-resolving.org")),
+        ];
+
+        for (input, expected) in examples.iter() {
+            let identifier = Identifier::parse(input);
+
+            assert_eq!(
+                identifier.resolver_host(),
+                *expected,
+                "Expected resolver host of {} to be {:?}",
+                input,
+                expected
+            );
+
+            // Must be consistent with the URI.
+            assert_eq!(
+                identifier.to_uri().as_deref().and_then(uri_host),
+                *expected,
+                "Expected host of URI of {} to be {:?}",
+                input,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn custom() {
+        assert_eq!(
+            Identifier::Custom {
+                scheme: String::from("grant"),
+                value: String::from("1234"),
+            }
+            .resolver_host(),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod custom_parser_tests {
+    use super::*;
+
+    /// Grant numbers like "GRANT-1234", normalised to the number.
+    struct GrantParser;
+
+    impl CustomParser for GrantParser {
+        fn scheme(&self) -> &str {
+            "grant"
+        }
+
+        fn try_parse(&self, input: &str) -> Option<String> {
+            input
+                .strip_prefix("GRANT-")
+                .filter(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+                .map(String::from)
+        }
+    }
+
+    #[test]
+    fn custom() {
+        let grant = Identifier::parse_with("GRANT-1234", &[&GrantParser]);
+
+        assert_eq!(
+            grant,
+            Identifier::Custom {
+                scheme: String::from("grant"),
+                value: String::from("1234"),
+            }
+        );
+
+        assert_eq!(grant.identifier_type(), IdentifierType::Custom);
+        assert_eq!(grant.to_uri(), None);
+        assert_eq!(grant.to_stable_string(), "grant:1234");
+        assert_eq!(
+            grant.to_id_string_pair(),
+            (String::from("grant:1234"), TYPE_ID_CUSTOM)
+        );
+    }
+
+    /// A colon in the scheme mustn't make two different identifiers look the same.
+    #[test]
+    fn colon_in_scheme() {
+        let a = Identifier::Custom {
+            scheme: String::from("a:b"),
+            value: String::from("c"),
+        };
+        let b = Identifier::Custom {
+            scheme: String::from("a"),
+            value: String::from("b:c"),
+        };
+
+        assert_ne!(a, b);
+        assert_ne!(a.cmp(&b), Ordering::Equal);
+        assert_ne!(a.to_stable_string(), b.to_stable_string());
+        assert_ne!(a.comparison_key(), b.comparison_key());
+        assert_eq!(a.to_stable_string(), "a%3Ab:c");
+        assert_eq!(b.to_stable_string(), "a:b:c");
+    }
+
+    #[test]
+    fn built_in_types_first() {
+        assert_eq!(
+            Identifier::parse_with("10.5555/12345678", &[&GrantParser]),
+            Identifier::parse("10.5555/12345678")
+        );
+    }
+
+    #[test]
+    fn default_parse_unchanged() {
+        assert_ne!(
+            Identifier::parse("GRANT-1234").identifier_type(),
+            IdentifierType::Custom
+        );
+
+        assert_eq!(
+            Identifier::parse_with("GRANT-abc", &[&GrantParser]),
+            Identifier::parse("GRANT-abc"),
+            "Unrecognised by the custom parser, so falls through as normal."
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_bytes_tests {
+    use super::*;
+
+    #[test]
+    fn valid_utf8() {
+        assert_eq!(
+            Identifier::parse_bytes(b"10.5555/12345678"),
+            Identifier::parse("10.5555/12345678")
+        );
+
+        assert_eq!(
+            Identifier::parse_bytes("10.5555/caf\u{e9}".as_bytes()),
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("caf\u{e9}"),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            Identifier::parse_bytes(b"10.5555/caf\xe9"),
+            Identifier::String(String::from("10.5555/caf\u{FFFD}")),
+            "Invalid UTF-8 shouldn't be rescued into a DOI."
+        );
+
+        assert_eq!(
+            Identifier::parse_bytes(b"\xff\xfe"),
+            Identifier::String(String::from("\u{FFFD}\u{FFFD}"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_lines_tests {
+    use super::*;
+
+    #[test]
+    fn same_as_parse() {
+        let inputs = [
+            "https://doi.org/10.5555/ABC",
+            "0306406152",
+            "hello world",
+            "",
+        ];
+
+        let lines: Vec<String> = inputs.iter().map(|input| String::from(*input)).collect();
+
+        assert_eq!(
+            Identifier::parse_lines(lines.into_iter()).collect::<Vec<Identifier>>(),
+            inputs
+                .iter()
+                .map(|input| Identifier::parse(input))
+                .collect::<Vec<Identifier>>()
+        );
+    }
+
+    #[test]
+    fn lazy() {
+        let lines = core::iter::repeat_with(|| String::from("0306406152"));
+
+        assert_eq!(
+            Identifier::parse_lines(lines).nth(2),
+            Some(Identifier::Isbn(String::from("9780306406157"))),
+            "An unbounded input should only be parsed as far as it's consumed."
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_with_original_tests {
+    use super::*;
+
+    #[test]
+    fn original_preserved() {
+        let parsed = Identifier::parse_with_original("https://doi.org/10.5555/ABC");
+        assert_eq!(
+            parsed.identifier,
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("abc")
+            }
+        );
+        assert_eq!(
+            parsed.original, "https://doi.org/10.5555/ABC",
+            "Case should be preserved."
+        );
+
+        let parsed = Identifier::parse_with_original("978 0 306 40615 7");
+        assert_eq!(
+            parsed.identifier,
+            Identifier::Isbn(String::from("9780306406157"))
+        );
+        assert_eq!(
+            parsed.original, "978 0 306 40615 7",
+            "Whitespace should be preserved."
+        );
+    }
+
+    #[test]
+    fn same_as_parse() {
+        for input in ["hello world", "https://example.com", "0306406152"] {
+            assert_eq!(
+                Identifier::parse_with_original(input).identifier,
+                Identifier::parse(input)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod describe_tests {
+    use super::*;
+
+    #[test]
+    fn doi() {
+        assert_eq!(
+            Identifier::parse("doi:10.5555/12345678").describe(),
+            IdentifierDescription {
+                type_name: "doi",
+                normalised: String::from("https://doi.org/10.5555/12345678"),
+                uri: Some(String::from("https://doi.org/10.5555/12345678")),
+                stable_string: String::from("https://doi.org/10.5555/12345678"),
+            }
+        );
+    }
+
+    #[test]
+    fn isbn() {
+        assert_eq!(
+            Identifier::parse("0306406152").describe(),
+            IdentifierDescription {
+                type_name: "isbn",
+                normalised: String::from("urn:isbn:9780306406157"),
+                uri: Some(String::from("urn:isbn:9780306406157")),
+                stable_string: String::from("9780306406157"),
+            }
+        );
+    }
+
+    #[test]
+    fn no_uri() {
+        let description = Identifier::parse("hello world").describe();
+
+        assert_eq!(description.type_name, "string");
+        assert_eq!(description.uri, None);
+        assert_eq!(description.normalised, description.stable_string);
+    }
+}
+
+#[cfg(test)]
+mod parse_options_tests {
+    use super::*;
+
+    /// The default options reproduce [`Identifier::parse`] exactly, including case.
+    #[test]
+    fn default_same_as_parse() {
+        let examples = [
+            "10.5555/ABC",
+            "https://doi.org/10.5555/ABC.",
+            "https://orcid.org/0000-0002-1694-233x",
+            "https://sandbox.orcid.org/0000-0002-1694-233X",
+            "https://ror.org/02twcfp32",
+            "0-306-40615-2",
+            "ISBN  0 306 40615 2",
+            "ark:/12345/X6np1wh8k",
+            "979-0-2600-0043-8",
+            "a-1234-2010",
+            "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+            "GRID.5072.0",
+            "(OCoLC)12345678",
+            "(DE-588)118540238",
+            "pub.1234567890",
+            "CorpusID:12345678",
+            "http://purl.org/dc/terms/Title",
+            "us-rc1-17-00274",
+            "0a9-2002-12b4a105-7",
+            "NM_001301717",
+            "10/ABCDE",
+            "t-034.524.680-1",
+            "pdb_00001abc",
+            "URN:NBN:de:bsz:93-opus-59016",
+            "http://Example.com:80/Path",
+            "hello world",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::parse_with_options(example, &ParseOptions::default()),
+                Identifier::parse(example),
+                "Expected default options to parse {} the same",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn sandbox_orcid() {
+        let options = ParseOptions {
+            sandbox_orcid: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Identifier::parse_with_options(
+                "https://sandbox.orcid.org/0000-0002-1694-233X",
+                &options
+            ),
+            Identifier::Orcid(String::from("0000-0002-1694-233X"))
+        );
+
+        assert_eq!(
+            Identifier::parse_with_options("https://orcid.org/0000-0002-1694-233X", &options),
+            Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            "Production iDs are still recognised."
+        );
+
+        assert_ne!(
+            Identifier::parse("https://sandbox.orcid.org/0000-0002-1694-233X").identifier_type(),
+            IdentifierType::Orcid,
+            "Sandbox iDs aren't ORCIDs by default."
+        );
+    }
+}
+
+#[cfg(test)]
+mod candidate_types_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn overlapping() {
+        assert_eq!(
+            Identifier::candidate_types("978-0-306-40615-7"),
+            [IdentifierType::Isbn, IdentifierType::Uri],
+            "The URI parser is greedy, so also matches an ISBN."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn first_is_parsed_type() {
+        let examples = [
+            "10.5555/12345678",
+            "https://orcid.org/0000-0002-1694-233X",
+            "https://ror.org/02twcfp32",
+            "0306406152",
+            "https://example.com",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::candidate_types(example).first(),
+                Some(&Identifier::parse(example).identifier_type()),
+                "Expected first candidate type of {} to be its parsed type",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(Identifier::candidate_types("hello world"), []);
+    }
+}
+
+#[cfg(test)]
+mod parse_typed_tests {
+    use super::*;
+
+    #[test]
+    fn matches_variant() {
+        let examples = [
+            "10.5555/12345678",
+            "https://orcid.org/0000-0002-1694-233X",
+            "https://ror.org/02twcfp32",
+            "0306406152",
+            "grid.5072.0",
+            "https://example.com",
+            "hello world",
+        ];
+
+        for example in examples.iter() {
+            let (identifier, identifier_type) = Identifier::parse_typed(example);
+
+            assert_eq!(
+                identifier.identifier_type(),
+                identifier_type,
+                "Expected type of {} to match its variant",
+                example
+            );
+
+            assert_eq!(Identifier::parse(example), identifier);
+        }
+    }
+}
+
+#[cfg(test)]
+mod comparison_key_tests {
+    use super::*;
+
+    #[test]
+    fn same_identifier() {
+        let examples = [
+            (
+                "10.5555/12345678",
+                "https://doi.org/10.5555/12345678",
+                "d:10.5555/12345678",
+            ),
+            ("0306406152", "978-0-306-40615-7", "i:9780306406157"),
+            (
+                "http://orcid.org/0000-0002-1694-233X",
+                "https://orcid.org/0000-0002-1694-233X",
+                "o:0000-0002-1694-233X",
+            ),
+        ];
+
+        for (a, b, expected) in examples.iter() {
+            assert_eq!(Identifier::parse(a).comparison_key(), *expected);
+            assert_eq!(Identifier::parse(b).comparison_key(), *expected);
+        }
+    }
+
+    #[test]
+    fn types_dont_collide() {
+        // Same value, different types.
+        let examples = [
+            Identifier::Ror(String::from("02twcfp32")),
+            Identifier::Uri(String::from("02twcfp32")),
+            Identifier::String(String::from("02twcfp32")),
+            Identifier::Grid(String::from("02twcfp32")),
+            Identifier::Oclc(String::from("02twcfp32")),
+            Identifier::Gnd(String::from("02twcfp32")),
+            Identifier::Isbn(String::from("02twcfp32")),
+            Identifier::Ismn(String::from("02twcfp32")),
+            Identifier::Orcid(String::from("02twcfp32")),
+            Identifier::ResearcherId(String::from("02twcfp32")),
+            Identifier::Swhid(String::from("02twcfp32")),
+            Identifier::Dimensions(String::from("02twcfp32")),
+        ];
+
+        for (i, a) in examples.iter().enumerate() {
+            for b in examples.iter().skip(i + 1) {
+                assert_ne!(
+                    a.comparison_key(),
+                    b.comparison_key(),
+                    "Expected {:?} and {:?} to have different keys",
+                    a,
+                    b
+                );
+            }
+        }
+
+        assert_ne!(
+            Identifier::Doi {
+                prefix: String::from("10.5555"),
+                suffix: String::from("12345678"),
+            }
+            .comparison_key(),
+            Identifier::Ark {
+                naan: String::from("10.5555"),
+                name: String::from("12345678"),
+            }
+            .comparison_key()
+        );
+    }
+}
+
+#[cfg(test)]
+mod panic_tests {
+    use super::*;
+
+    #[test]
+    fn pathological() {
+        let examples = [
+            String::new(),
+            String::from("/"),
+            "/".repeat(10_000),
+            String::from(":"),
+            String::from("\0"),
+            String::from("%"),
+            String::from("%FF%FE"),
+            String::from("10."),
+            String::from("10./"),
+            String::from("ark:/"),
+            String::from("(OCoLC)"),
+            String::from("swh:1:"),
+            "10.".repeat(10_000),
+            "https://doi.org/".repeat(10_000),
+            "doi:".repeat(10_000),
+            "https://".repeat(10_000),
+            format!("https://doi.org/10.5555/{}", "%".repeat(10_000)),
+            format!("https://ror.org/{}", "0".repeat(10_000)),
+            "9".repeat(10_000),
+            "X-".repeat(10_000),
+            "\u{10FFFF}".repeat(1_000),
+        ];
+
+        for example in examples.iter() {
+            let identifier = Identifier::parse(example);
+
+            identifier.to_uri();
+            identifier.to_stable_string();
+            identifier.normalise();
+            Identifier::parse_with_trace(example);
+            Identifier::extract_all(example);
+
+            for identifier_type in IdentifierType::all() {
+                Identifier::from_id_string_pair(example, identifier_type.id());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod constructor_tests {
+    use super::*;
+
+    #[test]
+    fn doi() {
+        assert_eq!(
+            Identifier::doi("10.5555", "ABC/def"),
+            Ok(Identifier::parse("https://doi.org/10.5555/abc/def")),
+            "Suffix should be lower-cased, and may contain slashes."
+        );
+
+        assert_eq!(
+            Identifier::doi("10.5555", "🦆"),
+            Ok(Identifier::parse("10.5555/🦆")),
+            "Suffix is not URL-encoded."
+        );
+    }
+
+    #[test]
+    fn invalid_doi() {
+        let examples = [
+            ("11.5555", "12345678"),
+            ("10.5555/12", "345678"),
+            ("10.", "12345678"),
+            ("10.5555", ""),
+        ];
+
+        for (prefix, suffix) in examples.iter() {
+            assert_eq!(
+                Identifier::doi(prefix, suffix),
+                Err(ParseError::Invalid(IdentifierType::Doi)),
+                "Expected {} and {} not to be a DOI",
+                prefix,
+                suffix
+            );
+        }
+    }
+
+    #[test]
+    fn orcid() {
+        assert_eq!(
+            Identifier::orcid("0000-0002-1694-233x"),
+            Ok(Identifier::parse("https://orcid.org/0000-0002-1694-233X")),
+            "Check digit should be upper-cased."
+        );
+    }
+
+    #[test]
+    fn isbn13() {
+        assert_eq!(
+            Identifier::isbn13("9780306406157"),
+            Ok(Identifier::parse("0-306-40615-2"))
+        );
+    }
+
+    #[test]
+    fn invalid_isbn13() {
+        let examples = [
+            // Bad check digit.
+            "9780306406158",
+            // Separators aren't accepted.
+            "978-0-306-40615-7",
+            // 10 digit ISBNs aren't accepted.
+            "0306406152",
+            // No Bookland prefix.
+            "4006381333931",
+            // Reserved for ISMNs.
+            "9790260000438",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::isbn13(example),
+                Err(ParseError::Invalid(IdentifierType::Isbn)),
+                "Expected {} not to be an ISBN",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_orcid() {
+        let examples = [
+            // Resolver isn't accepted.
+            "https://orcid.org/0000-0002-1694-233X",
+            // Wrong structure.
+            "0000-0002-1694",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::orcid(example),
+                Err(ParseError::Invalid(IdentifierType::Orcid)),
+                "Expected {} not to be an ORCID",
+                example
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod checksum_mismatch_tests {
+    use super::*;
+
+    #[test]
+    fn orcid() {
+        let expected = Err(ParseError::ChecksumMismatch {
+            kind: IdentifierType::Orcid,
+        });
+
+        assert_eq!(Identifier::orcid("0000-0002-1694-2330"), expected);
+        assert_eq!(Identifier::parse_strict("0000-0002-1694-2330"), expected);
+        assert_eq!(
+            Identifier::parse_strict("https://orcid.org/0000-0002-1694-2330"),
+            expected,
+            "URLs shouldn't fall through to URI."
+        );
+    }
+
+    #[test]
+    fn valid_orcid() {
+        let expected = Ok(Identifier::Orcid(String::from("0000-0002-1694-233X")));
+
+        assert_eq!(
+            Identifier::parse_strict("0000-0002-1694-233X"),
+            expected,
+            "A bare iD is recognised when the check digit is right, as well as reported when it's wrong."
+        );
+        assert_eq!(
+            Identifier::parse_strict("https://orcid.org/0000-0002-1694-233X"),
+            expected
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn not_orcid() {
+        assert_eq!(
+            Identifier::parse_strict("https://example.com/0000-0002-1694-2330"),
+            Ok(Identifier::Uri(String::from(
+                "https://example.com/0000-0002-1694-2330"
+            ))),
+            "Only orcid.org URLs are nearly ORCIDs."
+        );
+
+        assert_eq!(
+            Identifier::orcid("0000-0002-1694"),
+            Err(ParseError::Invalid(IdentifierType::Orcid))
+        );
+    }
+}
+
+#[cfg(test)]
+mod bibtex_tests {
+    use super::*;
+
+    #[test]
+    fn doi() {
+        assert_eq!(
+            Identifier::parse("https://doi.org/10.5555/%3C%3E").to_bibtex_field(),
+            Some(("doi", String::from("10.5555/<>"))),
+            "DOIs should be the bare name, not URL-encoded."
+        );
+    }
+
+    #[test]
+    fn isbn() {
+        assert_eq!(
+            Identifier::parse("0306406152").to_bibtex_field(),
+            Some(("isbn", String::from("978-0-306-40615-7")))
+        );
+
+        assert_eq!(
+            Identifier::parse("9783161484100").to_bibtex_field(),
+            Some(("isbn", String::from("9783161484100"))),
+            "Unknown registration groups can't be hyphenated."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn url() {
+        let examples = [
+            (
+                "orcid.org/0000-0002-1694-233X",
+                "https://orcid.org/0000-0002-1694-233X",
+            ),
+            ("https://ror.org/02twcfp32", "https://ror.org/02twcfp32"),
+            ("https://example.com/path", "https://example.com/path"),
+        ];
+
+        for (input, expected) in examples.iter() {
+            assert_eq!(
+                Identifier::parse(input).to_bibtex_field(),
+                Some(("url", String::from(*expected))),
+                "Expected {} to be a url field",
+                input
+            );
+        }
+
+        assert_eq!(Identifier::parse("hello world").to_bibtex_field(), None);
+    }
+}
+
+#[cfg(test)]
+mod try_to_uri_tests {
+    use super::*;
+
+    #[test]
+    fn doi() {
+        assert_eq!(
+            Identifier::parse("10.5555/🦆").try_to_uri(),
+            Ok(String::from("https://doi.org/10.5555/%F0%9F%A6%86"))
+        );
+    }
+
+    #[test]
+    fn other_types() {
+        assert_eq!(
+            Identifier::parse("https://ror.org/02twcfp32").try_to_uri(),
+            Ok(String::from("https://ror.org/02twcfp32"))
+        );
+    }
+
+    #[test]
+    fn no_uri() {
+        assert_eq!(
+            Identifier::String(String::from("hello")).try_to_uri(),
+            Err(EncodeError::NoUri)
+        );
+
+        assert_eq!(
+            Identifier::Custom {
+                scheme: String::from("grant"),
+                value: String::from("ABC-123"),
+            }
+            .try_to_uri(),
+            Err(EncodeError::NoUri)
+        );
+    }
+}
+
+#[cfg(test)]
+mod resolvable_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn resolvable() {
+        let inputs = [
+            // DOI
+            "10.5555/12345678",
+            // ISBN
+            "0306406152",
+            // ORCID
+            "https://orcid.org/0000-0002-1694-233X",
+            // ROR
+            "https://ror.org/02twcfp32",
+            // ARK
+            "ark:/12345/x6np1wh8k",
+            // URI
+            "https://example.com",
+        ];
+
+        for input in inputs.iter() {
+            assert!(
+                Identifier::parse(input).is_resolvable(),
+                "Expected {} to be resolvable",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn web_url() {
+        assert_eq!(
+            Identifier::parse("10.5555/12345678").to_web_url(),
+            Some(String::from("https://doi.org/10.5555/12345678"))
+        );
+
+        assert_eq!(
+            Identifier::parse("urn:uuid:6e8bc430-9c3a-11d9-9669-0800200c9a66").to_web_url(),
+            None,
+            "URIs that aren't web links have no web URL."
+        );
+
+        assert_eq!(Identifier::parse("hello world").to_web_url(), None);
+    }
+
+    #[test]
+    fn not_resolvable() {
+        assert!(!Identifier::parse("hello world").is_resolvable());
+    }
+}
+
+#[cfg(test)]
+mod normalise_tests {
+    use super::*;
+
+    #[test]
+    fn normalise() {
+        let examples = [
+            ("doi:10.5555/12345678", "https://doi.org/10.5555/12345678"),
+            (
+                "http://orcid.org/0000-0002-1694-233X",
+                "https://orcid.org/0000-0002-1694-233X",
+            ),
+            ("http://ror.org/02twcfp32", "https://ror.org/02twcfp32"),
+            ("https://example.com/", "https://example.com/"),
+            ("hello world", "hello world"),
+            ("0306406152", "urn:isbn:9780306406157"),
+            (
+                "ark:/12345/x6np1wh8k",
+                "https://n2t.net/ark:/12345/x6np1wh8k",
+            ),
+            ("979-0-2600-0043-8", "9790260000438"),
+        ];
+
+        for (input, expected) in examples.iter() {
+            assert_eq!(
+                Identifier::parse(input).normalise(),
+                *expected,
+                "Expected {} to normalise to {}",
+                input,
+                expected
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod type_id_tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn constants_round_trip() {
+        let examples = [
+            (TYPE_ID_DOI, IdentifierType::Doi, "10.5555/12345678"),
+            (
+                TYPE_ID_ORCID,
+                IdentifierType::Orcid,
+                "https://orcid.org/0000-0002-1694-233X",
+            ),
+            (
+                TYPE_ID_ROR,
+                IdentifierType::Ror,
+                "https://ror.org/02twcfp32",
+            ),
+            (TYPE_ID_URI, IdentifierType::Uri, "https://example.com"),
+            (TYPE_ID_STRING, IdentifierType::String, "hello world"),
+            (TYPE_ID_ISBN, IdentifierType::Isbn, "0306406152"),
+            (TYPE_ID_ARK, IdentifierType::Ark, "ark:/12345/x6np1wh8k"),
+            (TYPE_ID_ISMN, IdentifierType::Ismn, "979-0-2600-0043-8"),
+            (
+                TYPE_ID_RESEARCHERID,
+                IdentifierType::ResearcherId,
+                "A-1234-2010",
+            ),
+            (
+                TYPE_ID_SWHID,
+                IdentifierType::Swhid,
+                "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+            ),
+            (TYPE_ID_GRID, IdentifierType::Grid, "grid.5072.0"),
+            (TYPE_ID_OCLC, IdentifierType::Oclc, "(OCoLC)12345678"),
+            (TYPE_ID_GND, IdentifierType::Gnd, "(DE-588)118540238"),
+            (
+                TYPE_ID_DIMENSIONS,
+                IdentifierType::Dimensions,
+                "pub.1234567890",
+            ),
+            (
+                TYPE_ID_SEMANTICSCHOLAR,
+                IdentifierType::SemanticScholar,
+                "CorpusID:12345678",
+            ),
+            (
+                TYPE_ID_PURL,
+                IdentifierType::Purl,
+                "https://purl.org/dc/terms/title",
+            ),
+            (TYPE_ID_ISRC, IdentifierType::Isrc, "US-RC1-17-00274"),
+            (TYPE_ID_ISTC, IdentifierType::Istc, "0A9-2002-12B4A105-7"),
+            (TYPE_ID_ACCESSION, IdentifierType::Accession, "NM_001301717"),
+            (
+                TYPE_ID_SHORTDOI,
+                IdentifierType::ShortDoi,
+                "https://doi.org/10/abcde",
+            ),
+            (TYPE_ID_ISWC, IdentifierType::Iswc, "T-034.524.680-1"),
+            (TYPE_ID_PDB, IdentifierType::Pdb, "1ABC"),
+            (
+                TYPE_ID_URNNBN,
+                IdentifierType::UrnNbn,
+                "urn:nbn:fi-fe20031153",
+            ),
+        ];
+
+        for (type_id, identifier_type, input) in examples.iter() {
+            let parsed = Identifier::parse(input);
+            assert_eq!(parsed.identifier_type(), *identifier_type);
+            assert_eq!(identifier_type.id(), *type_id);
+
+            let (value, pair_type_id) = parsed.to_id_string_pair();
+            assert_eq!(pair_type_id, *type_id);
+
+            let from_pair = Identifier::from_id_string_pair(&value, *type_id)
+                .expect("Expected pair to round-trip");
+            assert_eq!(from_pair.identifier_type(), *identifier_type);
+        }
+    }
+
+    #[test]
+    fn all_types() {
+        let all = IdentifierType::all();
+
+        // Type ids are allocated in sequence, so there should be one type for each.
+        for (index, identifier_type) in all.iter().enumerate() {
+            assert_eq!(identifier_type.id() as usize, index + 1);
+        }
+
+        for identifier_type in all.iter() {
+            let name = identifier_type.name();
+            assert_eq!(name, name.to_lowercase(), "Name should be lower-case.");
+
+            assert_eq!(
+                all.iter().filter(|other| other.name() == name).count(),
+                1,
+                "Name {} should be unique.",
+                name
+            );
+        }
+    }
+
+    /// Every type except the fall-through String and Custom has exactly one parser, which `from_id_string_pair` relies
+    /// on.
+    #[test]
+    fn one_parser_per_type() {
+        for identifier_type in IdentifierType::all() {
+            let expected = if matches!(
+                identifier_type,
+                IdentifierType::String | IdentifierType::Custom
+            ) {
+                0
+            } else {
+                1
+            };
+
+            assert_eq!(
+                PARSERS
+                    .iter()
+                    .filter(|parser| parser.type_id() == identifier_type.id())
+                    .count(),
+                expected,
+                "Expected {} parsers for {:?}",
+                expected,
+                identifier_type
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn names_match_serde() {
+        for identifier_type in IdentifierType::all().iter() {
+            assert_eq!(
+                serde_json::to_string(identifier_type).unwrap(),
+                format!("\"{}\"", identifier_type.name())
+            );
+        }
+    }
+
+    #[test]
+    fn mismatched_type_id() {
+        let (value, _) =
+            Identifier::parse("https://orcid.org/0000-0002-1694-233X").to_id_string_pair();
+
+        assert_eq!(
+            Identifier::from_id_string_pair(&value, TYPE_ID_DOI),
+            None,
+            "ORCID value with DOI type id should not be accepted."
+        );
+
+        assert_eq!(
+            Identifier::from_id_string_pair("10.5555/12345678", TYPE_ID_ISBN),
+            None,
+            "DOI value with ISBN type id should not be accepted."
+        );
+
+        assert_eq!(
+            Identifier::from_id_string_pair("0306406152", 999),
+            None,
+            "Unknown type id should not be accepted."
+        );
+    }
+}
+
+#[cfg(test)]
+mod id_string_pair_conversion_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let examples = [
+            "10.5555/12345678",
+            "https://orcid.org/0000-0002-1694-233X",
+            "https://ror.org/02twcfp32",
+            "https://example.com",
+            "hello world",
+            "0306406152",
+            "ark:/12345/x6np1wh8k",
+            "979-0-2600-0043-8",
+            "A-1234-2010",
+            "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+            "grid.5072.0",
+            "(OCoLC)12345678",
+            "(DE-588)118540238",
+            "pub.1234567890",
+            "CorpusID:12345678",
+            "https://purl.org/dc/terms/title",
+            "US-RC1-17-00274",
+            "0A9-2002-12B4A105-7",
+            "NM_001301717",
+            "10/abcde",
+            "T-034.524.680-1",
+            "1ABC",
+            "urn:nbn:fi-fe20031153",
+        ];
+
+        for example in examples.iter() {
+            let identifier = Identifier::parse(example);
+
+            let pair: (String, u32) = (&identifier).into();
+            assert_eq!(pair, identifier.to_id_string_pair());
+
+            let round_tripped: Identifier = pair.try_into().expect("Expected pair to round-trip");
+            assert_eq!(
+                identifier, round_tripped,
+                "Expected {} to round-trip",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Identifier::try_from((String::from("0306406152"), 999)),
+            Err(ParseError::UnknownTypeId(999))
+        );
+
+        assert_eq!(
+            Identifier::try_from((String::from("10.5555/12345678"), TYPE_ID_ISBN)),
+            Err(ParseError::Invalid(IdentifierType::Isbn))
+        );
+
+        assert_eq!(
+            Identifier::try_from((String::from("ABC-123"), TYPE_ID_CUSTOM)),
+            Err(ParseError::Invalid(IdentifierType::Custom)),
+            "Custom identifiers need a scheme."
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let examples = [
+            ("grant", "ABC-123"),
+            ("a:b", "c"),
+            ("a", "b:c"),
+            ("50%", "x"),
+            ("%3A", "y"),
+        ];
+
+        for (scheme, value) in examples.iter() {
+            let identifier = Identifier::Custom {
+                scheme: String::from(*scheme),
+                value: String::from(*value),
+            };
+
+            let round_tripped: Identifier = identifier
+                .to_id_string_pair()
+                .try_into()
+                .expect("Expected pair to round-trip");
+
+            assert_eq!(
+                identifier, round_tripped,
+                "Expected {}:{} to round-trip",
+                scheme, value
+            );
+        }
+    }
+}
+
+/// Count allocations on the current thread, to check that parsing canonical inputs doesn't copy them.
+#[cfg(all(test, feature = "std"))]
+mod allocation_tests {
+    use super::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    struct CountingAllocator;
+
+    std::thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Fewest allocations made by `f` over a number of runs.
+    /// The first run initialises the regexes, and other test threads may briefly share regex caches, so take the minimum.
+    fn min_allocations<F: Fn()>(f: F) -> usize {
+        (0..10)
+            .map(|_| {
+                let before = ALLOCATIONS.with(|count| count.get());
+                f();
+                ALLOCATIONS.with(|count| count.get()) - before
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn lowercase_raw_doi_input_not_copied() {
+        let input = "10.5555/12345678";
+
+        assert_eq!(
+            min_allocations(|| {
+                let parse_input = IdentifierParseInput::build(input);
+                assert!(matches!(parse_input.raw_lowercase(), Cow::Borrowed(_)));
+                assert!(doi::try_parse(&parse_input).is_some());
+            }),
+            // One each for the prefix and suffix of the resulting Identifier.
+            2,
+            "Expected only the resulting Identifier to allocate."
+        );
+
+        assert_eq!(
+            min_allocations(|| {
+                Identifier::parse(input);
+            }),
+            2,
+            "Expected only the resulting Identifier to allocate."
+        );
+    }
+
+    #[test]
+    fn mixed_case_doi_input_copied() {
+        let parse_input = IdentifierParseInput::build("10.5555/ABCDEF");
+        assert!(matches!(parse_input.raw_lowercase(), Cow::Owned(_)));
+    }
+}
@@ -8,7 +8,75 @@
 ///
 /// ISBNs can optionally be formatted with hyphens. These are removed upon
 /// parsing.
-use crate::identifiers::{Identifier, IdentifierParseInput};
+use crate::identifiers::{Identifier, IdentifierParseInput, IsbnParts};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Maximum number of separators in a 10-digit ISBN, between group, registrant, publication and check digit.
+const TEN_DIGIT_MAX_SEPARATORS: usize = 3;
+
+/// Maximum number of separators in a 13-digit ISBN, which also has a prefix element.
+const THIRTEEN_DIGIT_MAX_SEPARATORS: usize = 4;
+
+/// Characters that separate the elements of an ISBN, and are ignored.
+/// As well as hyphens and spaces, ISBNs copied from PDFs and word processors often contain typographic dashes and
+/// spaces.
+/// Shared with ISMNs, which are written the same way.
+pub(crate) const SEPARATORS: &[char] = &[
+    // Space, and non-breaking, thin and narrow non-breaking spaces.
+    ' ', '\u{00A0}', '\u{2009}', '\u{202F}',
+    // Hyphen-minus, hyphen, non-breaking hyphen, figure dash, en dash, em dash and minus sign.
+    '-', '\u{2010}', '\u{2011}', '\u{2012}', '\u{2013}', '\u{2014}', '\u{2212}',
+];
+
+/// Bookland prefixes. Every 13 digit ISBN starts with one of these.
+const BOOKLAND_PREFIXES: &[&[u32]] = &[&[9, 7, 8], &[9, 7, 9]];
+
+/// Start of an ISBN-A DOI prefix, after the "10." directory indicator.
+const ISBN_A_PREFIXES: &[&str] = &["978.", "979."];
+
+/// Prefix of 13 digit ISMNs, which share the 979 Bookland prefix.
+const ISMN_PREFIX: &[u32] = &[9, 7, 9, 0];
+
+/// Textual labels that catalogue records put before an ISBN, in upper case, longest first.
+const LABELS: &[&str] = &["ISBN-10", "ISBN-13", "ISBN"];
+
+/// Length of the EAN prefix.
+const PREFIX_LENGTH: usize = 3;
+
+/// A range of registrants, as the first and last 7 digits after the group, padded with zeros, and the length of the
+/// registrant.
+type RegistrantRange = (u32, u32, usize);
+
+/// Registrant ranges that are the same in the English (0) and Japanese (4) language areas.
+const COMMON_RANGES: &[RegistrantRange] = &[
+    (0, 1999999, 2),
+    (2000000, 6999999, 3),
+    (7000000, 8499999, 4),
+    (8500000, 8999999, 5),
+    (9000000, 9499999, 6),
+    (9500000, 9999999, 7),
+];
+
+/// Registrant ranges of the English language area (1).
+const ENGLISH_1_RANGES: &[RegistrantRange] = &[
+    (0, 999999, 2),
+    (1000000, 3999999, 3),
+    (4000000, 5499999, 4),
+    (5500000, 8697999, 5),
+    (8698000, 9989999, 6),
+    (9990000, 9999999, 7),
+];
+
+/// Registrant ranges of each registration group in the table, keyed by the EAN prefix and group.
+/// See <https://www.isbn-international.org/range_file_generation>.
+const REGISTRANT_RANGES: &[(&str, &str, &[RegistrantRange])] = &[
+    ("978", "0", COMMON_RANGES),
+    ("978", "1", ENGLISH_1_RANGES),
+    ("978", "4", COMMON_RANGES),
+];
 
 /// Weights of the numbers 0 to 9 for 10-digit validation.
 const TEN_DIGIT_WEIGHTS: &[u32] = &[10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
@@ -19,12 +87,17 @@ const THIRTEEN_DIGIT_WEIGHTS: &[u32] = &[1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1];
 /// Try to parse a 10 or 13 digit ISBN. Return the digits normalized to 13
 /// digits. This enables the resulting value to be compared against another
 /// ISBN, whether it was expressed in 10 or 13 digit form.
+///
+/// Accepts the bare ISBN, the "urn:isbn:" URN, or the ISBN after a label such as "ISBN", "ISBN-10" or "ISBN-13", in
+/// any case and optionally followed by a colon, e.g. "ISBN: 978-0-306-40615-7".
 pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
-    let upcase = &input.raw.to_uppercase();
-    let less_prefix = upcase.strip_prefix("URN:ISBN:").unwrap_or(&input.raw);
+    let upcase = input.raw_uppercase();
+    let less_prefix = remove_prefixes(&upcase);
 
-    if let Some(digits) = str_to_digits(&less_prefix) {
-        if validate_10_digit(&digits) {
+    if let Some(digits) = str_to_digits(less_prefix) {
+        if input.options.strict_isbn_separators && !validate_separators(less_prefix, &digits) {
+            None
+        } else if validate_10_digit(&digits) {
             let as_thirteen = ten_digit_to_thirteen_digit(&digits);
             Some(Identifier::Isbn(digits_to_str(&as_thirteen)))
         } else if validate_13_digit(&digits) {
@@ -37,6 +110,54 @@ pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
     }
 }
 
+/// Remove the URN prefix or a textual label from an upper-cased input.
+/// Whatever remains must still be only ISBN characters to be parsed.
+fn remove_prefixes(upcase: &str) -> &str {
+    if let Some(rest) = upcase.strip_prefix("URN:ISBN:") {
+        rest
+    } else if let Some(rest) = LABELS.iter().find_map(|label| upcase.strip_prefix(label)) {
+        rest.trim_start_matches(':').trim_start()
+    } else {
+        upcase
+    }
+}
+
+/// Construct from exactly 13 ASCII digits, with no separators, prefix or 10 digit conversion.
+/// The digits must have a Bookland prefix and a correct check digit.
+pub(crate) fn from_13_digits(input: &str) -> Option<Identifier> {
+    if input.len() != 13 || !input.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits: Vec<u32> = input.bytes().map(|byte| u32::from(byte - b'0')).collect();
+
+    if validate_13_digit(&digits) {
+        Some(Identifier::Isbn(String::from(input)))
+    } else {
+        None
+    }
+}
+
+/// If the input looks like an ISBN but isn't valid, explain why.
+pub(crate) fn near_miss(input: &IdentifierParseInput) -> Option<&'static str> {
+    let upcase = input.raw_uppercase();
+    let less_prefix = remove_prefixes(&upcase);
+
+    match str_to_digits(less_prefix) {
+        Some(digits) if digits.len() == 10 && !validate_10_digit(&digits) => {
+            Some("bad check digit")
+        }
+        Some(digits)
+            if digits.len() == 13
+                && has_bookland_prefix(&digits)
+                && !validate_13_digit(&digits) =>
+        {
+            Some("bad check digit")
+        }
+        _ => None,
+    }
+}
+
 /// Convert an ISBN to a URN URI.
 /// Follows <https://www.iana.org/assignments/urn-formal/isbn>.
 pub fn to_uri(input: &Identifier) -> Option<String> {
@@ -46,6 +167,15 @@ pub fn to_uri(input: &Identifier) -> Option<String> {
     }
 }
 
+/// Link to an ISBN's page on WorldCat, as the URN isn't a web link.
+/// Will always return a result if an ISBN type is supplied.
+pub(crate) fn to_web_url(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Isbn(ref value) => Some(format!("https://www.worldcat.org/isbn/{}", value)),
+        _ => None,
+    }
+}
+
 /// Encode an ISBN as a stable string.
 /// Will always return a String if an ISBN type is supplied.
 pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
@@ -55,14 +185,107 @@ pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
     }
 }
 
+/// Extract the ISBN from an ISBN-A, the actionable DOI form of an ISBN.
+/// The DOI prefix is the EAN prefix, registration group and registrant, and the suffix is the publication and check
+/// digit, e.g. "10.978.0306/406157" for ISBN 978-0-306-40615-7.
+/// Returns None if the input isn't an ISBN-A DOI, or if the ISBN isn't valid.
+pub(crate) fn from_isbn_a(input: &Identifier) -> Option<Identifier> {
+    match input {
+        Identifier::Doi { prefix, suffix } => {
+            let doi_prefix = prefix.strip_prefix("10.")?;
+
+            if !ISBN_A_PREFIXES
+                .iter()
+                .any(|isbn_a_prefix| doi_prefix.starts_with(isbn_a_prefix))
+            {
+                return None;
+            }
+
+            let combined = String::from_iter(
+                doi_prefix
+                    .chars()
+                    .chain(suffix.chars())
+                    .filter(|x| *x != '.'),
+            );
+
+            str_to_digits(&combined)
+                .filter(|digits| validate_13_digit(digits))
+                .map(|digits| Identifier::Isbn(digits_to_str(&digits)))
+        }
+        _ => None,
+    }
+}
+
+/// Split an ISBN into its elements, using the registrant ranges of its registration group.
+/// Returns None if the input isn't an ISBN, or if its registration group isn't in the table.
+/// The variant can be constructed directly, so a value that isn't 13 digits also gives None rather than a panic.
+pub(crate) fn parts(input: &Identifier) -> Option<IsbnParts> {
+    match input {
+        Identifier::Isbn(value) => {
+            if value.len() != 13 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+
+            let (prefix, group, ranges) = REGISTRANT_RANGES.iter().find(|(prefix, group, _)| {
+                value.starts_with(prefix)
+                    && value
+                        .get(PREFIX_LENGTH..)
+                        .is_some_and(|rest| rest.starts_with(group))
+            })?;
+
+            let (rest, check_digit) = value.split_at(value.len() - 1);
+            let rest = rest.get(PREFIX_LENGTH + group.len()..)?;
+
+            // Ranges are expressed as 7 digits, so pad short remainders with zeros.
+            let key: u32 = format!("{:0<7}", rest).get(..7)?.parse().ok()?;
+
+            let length = ranges
+                .iter()
+                .find(|(first, last, _)| (*first..=*last).contains(&key))
+                .map(|(_, _, length)| *length)
+                .filter(|length| *length < rest.len())?;
+
+            let (registrant, publication) = rest.split_at(length);
+
+            Some(IsbnParts {
+                prefix: String::from(*prefix),
+                registration_group: String::from(*group),
+                registrant: String::from(registrant),
+                publication: String::from(publication),
+                check_digit: String::from(check_digit),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Format an ISBN with hyphens between its elements, e.g. "978-0-306-40615-7".
+/// If the registration group isn't in the table, the elements aren't known, so this is the 13 digits.
+/// Returns None if the input isn't an ISBN.
+pub(crate) fn to_hyphenated(input: &Identifier) -> Option<String> {
+    match (input, parts(input)) {
+        (_, Some(parts)) => Some(format!(
+            "{}-{}-{}-{}-{}",
+            parts.prefix,
+            parts.registration_group,
+            parts.registrant,
+            parts.publication,
+            parts.check_digit
+        )),
+        (Identifier::Isbn(value), None) => Some(value.clone()),
+        _ => None,
+    }
+}
+
 /// Return vector of integers for 10 or 13 sized ISBN.
 /// If any invalid digits are found, return None.
 fn str_to_digits(input: &str) -> Option<Vec<u32>> {
     let chars = Vec::from_iter(input.chars());
 
+    // Only ASCII digits, not digits from other scripts.
     let bad = chars
         .iter()
-        .any(|x| !matches!(x, '0'..='9' | 'X' | 'x' | ' ' | '-'));
+        .any(|x| !matches!(x, '0'..='9' | 'X' | 'x') && !SEPARATORS.contains(x));
 
     if bad {
         return None;
@@ -78,6 +301,17 @@ fn str_to_digits(input: &str) -> Option<Vec<u32>> {
     Some(Vec::from_iter(digits))
 }
 
+/// Check that the input doesn't have more separators than a real ISBN of its length can have.
+fn validate_separators(input: &str, digits: &[u32]) -> bool {
+    let separators = input.chars().filter(|x| SEPARATORS.contains(x)).count();
+
+    match digits.len() {
+        10 => separators <= TEN_DIGIT_MAX_SEPARATORS,
+        13 => separators <= THIRTEEN_DIGIT_MAX_SEPARATORS,
+        _ => false,
+    }
+}
+
 fn digits_to_str(input: &[u32]) -> String {
     String::from_iter(input.iter().filter_map(|x| match x {
         0..=9 => char::from_digit(*x, 10),
@@ -94,7 +328,8 @@ fn generate_10_digit_checksum(digits: &[u32]) -> u32 {
         expected_checksum += digits[i] * TEN_DIGIT_WEIGHTS[i]
     }
 
-    11 - (expected_checksum % 11)
+    // A remainder of 0 gives a check digit of 0, not 11.
+    (11 - (expected_checksum % 11)) % 11
 }
 
 // Validate a candidate 10 digit ISBN.
@@ -110,26 +345,36 @@ fn validate_10_digit(digits: &[u32]) -> bool {
 /// Generate a checksum for a 13 digit ISBN from the first 12 digits.
 /// Accept whole ISBN including check digit.
 /// If validates, return the first digits.
-fn generate_13_digit_checksum(digits: &[u32]) -> u32 {
+/// This is the EAN-13 checksum, so is shared with other EAN-13 identifiers.
+pub(crate) fn generate_13_digit_checksum(digits: &[u32]) -> u32 {
     let mut result = 0;
     // Don't include check digit
     for i in 0..12 {
         result += digits[i] * THIRTEEN_DIGIT_WEIGHTS[i];
     }
 
-    10 - (result % 10)
+    // A remainder of 0 gives a check digit of 0, not 10.
+    (10 - (result % 10)) % 10
 }
 
 /// Validate and normalise 13 digit ISBN.
 /// Return None if invalid, or the normalised value as a string, including the check digit.
+/// EAN-13 codes without a 978 or 979 Bookland prefix, such as product barcodes, are not ISBNs.
+/// The 979-0 prefix is reserved for ISMNs, so is never an ISBN.
 fn validate_13_digit(digits: &[u32]) -> bool {
-    if digits.len() != 13 {
+    if digits.len() != 13 || !has_bookland_prefix(digits) || digits.starts_with(ISMN_PREFIX) {
         return false;
     }
     let expected_checksum = generate_13_digit_checksum(digits);
     expected_checksum == digits[12]
 }
 
+fn has_bookland_prefix(digits: &[u32]) -> bool {
+    BOOKLAND_PREFIXES
+        .iter()
+        .any(|prefix| digits.starts_with(prefix))
+}
+
 /// Convert 10 digit ISBN to 13 digit by prepending 978 and recalculating the check digit.
 fn ten_digit_to_thirteen_digit(digits: &[u32]) -> Vec<u32> {
     let mut new_isbn = vec![9, 7, 8];
@@ -145,6 +390,7 @@ fn ten_digit_to_thirteen_digit(digits: &[u32]) -> Vec<u32> {
 #[cfg(test)]
 mod isbn_parser_tests {
     use crate::identifiers::Identifier;
+    use alloc::string::String;
 
     /// Correct 10 digit ISBNs are converted to 13-digit ones, with correct check digit.
     #[test]
@@ -174,6 +420,20 @@ mod isbn_parser_tests {
         }
     }
 
+    /// Check digits of 0 are valid.
+    #[test]
+    fn zero_check_digit() {
+        assert_eq!(
+            Identifier::parse("0306406160"),
+            Identifier::Isbn(String::from("9780306406164"))
+        );
+
+        assert_eq!(
+            Identifier::parse("9780306406140"),
+            Identifier::Isbn(String::from("9780306406140"))
+        );
+    }
+
     #[test]
     fn hyphens_10() {
         let examples = [
@@ -216,8 +476,45 @@ mod isbn_parser_tests {
         }
     }
 
+    /// Typographic spaces and dashes are separators too.
+    #[test]
+    fn unicode_separators() {
+        let examples = [
+            "978\u{00A0}0\u{00A0}306\u{00A0}40615\u{00A0}7",
+            "0\u{2013}306\u{2013}40615\u{2013}2",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::parse(example),
+                Identifier::Isbn(String::from("9780306406157")),
+                "Expected {} to be an ISBN",
+                example
+            );
+        }
+
+        assert_eq!(
+            Identifier::parse("٠٣٠٦٤٠٦١٥٢"),
+            Identifier::String(String::from("٠٣٠٦٤٠٦١٥٢")),
+            "Digits from other scripts are not recognised."
+        );
+    }
+
+    /// EAN-13 codes without a Bookland prefix are not ISBNs, even with a valid check digit.
+    #[test]
+    #[cfg(feature = "std")]
+    fn non_bookland_13() {
+        let examples = ["4006381333931"];
+
+        for example in examples.iter() {
+            let result = Identifier::parse(example);
+            assert_eq!(result, Identifier::Uri(String::from(*example)));
+        }
+    }
+
     /// Bad checksums are not recognised as 10 digit ISBNs.
     #[test]
+    #[cfg(feature = "std")]
     fn bad_10() {
         let examples = ["0306406150"];
 
@@ -244,3 +541,352 @@ mod isbn_parser_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod isbn_strict_separator_tests {
+    use crate::identifiers::{Identifier, ParseOptions};
+    use alloc::string::String;
+
+    fn strict() -> ParseOptions {
+        ParseOptions {
+            strict_isbn_separators: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn over_separated() {
+        assert_eq!(
+            Identifier::parse_with_options("0-3-0-6-4-0-6-1-5-2", &strict()),
+            Identifier::Uri(String::from("0-3-0-6-4-0-6-1-5-2")),
+            "Over-separated ISBN-10 rejected in strict mode."
+        );
+
+        assert_eq!(
+            Identifier::parse_with_options("9-7-8-0-3-0-6-4-0-6-1-5-7", &strict()),
+            Identifier::Uri(String::from("9-7-8-0-3-0-6-4-0-6-1-5-7")),
+            "Over-separated ISBN-13 rejected in strict mode."
+        );
+
+        assert_eq!(
+            Identifier::parse("0-3-0-6-4-0-6-1-5-2"),
+            Identifier::Isbn(String::from("9780306406157")),
+            "Over-separated ISBN accepted in default lenient mode."
+        );
+    }
+
+    #[test]
+    fn normally_separated() {
+        let expected = Identifier::Isbn(String::from("9780306406157"));
+
+        assert_eq!(
+            Identifier::parse_with_options("0-306-40615-2", &strict()),
+            expected
+        );
+
+        assert_eq!(
+            Identifier::parse_with_options("978-0-306-40615-7", &strict()),
+            expected
+        );
+
+        assert_eq!(
+            Identifier::parse_with_options("9780306406157", &strict()),
+            expected
+        );
+    }
+}
+
+#[cfg(test)]
+mod isbn_urn_tests {
+    use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn urn() {
+        assert_eq!(
+            Some(String::from("urn:isbn:9780306406157")),
+            Identifier::parse("0-306-40615-2").to_urn()
+        );
+    }
+
+    #[test]
+    fn no_urn() {
+        assert_eq!(None, Identifier::parse("https://example.com").to_urn());
+    }
+}
+
+#[cfg(test)]
+mod isbn_label_tests {
+    use crate::identifiers::{Identifier, IdentifierType};
+    use alloc::string::String;
+
+    #[test]
+    fn labels() {
+        let examples = [
+            "ISBN: 978-0-306-40615-7",
+            "ISBN 978-0-306-40615-7",
+            "ISBN978-0-306-40615-7",
+            "isbn-13 9780306406157",
+            "ISBN-13: 978-0-306-40615-7",
+            "ISBN-10: 0-306-40615-2",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::Isbn(String::from("9780306406157")),
+                Identifier::parse(example),
+                "Expected {} to be an ISBN",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn not_isbn() {
+        let examples = [
+            "ISBN unknown",
+            "The ISBN is 978-0-306-40615-7",
+            "ISBN: 978-0-306-40615-7 (paperback)",
+            "ISBN:",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Isbn,
+                "Expected {} not to be an ISBN",
+                example
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod isbn_web_url_tests {
+    use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn web_url() {
+        let isbn = Identifier::parse("0306406152");
+
+        assert_eq!(
+            isbn.to_uri(),
+            Some(String::from("urn:isbn:9780306406157")),
+            "URI is a URN."
+        );
+
+        assert_eq!(
+            isbn.to_web_url(),
+            Some(String::from("https://www.worldcat.org/isbn/9780306406157")),
+            "Web URL is a link."
+        );
+    }
+}
+
+#[cfg(test)]
+mod isbn_parts_tests {
+    use crate::identifiers::{Identifier, IsbnParts};
+    use alloc::string::String;
+
+    #[test]
+    fn english() {
+        assert_eq!(
+            Identifier::parse("0306406152").isbn_parts(),
+            Some(IsbnParts {
+                prefix: String::from("978"),
+                registration_group: String::from("0"),
+                registrant: String::from("306"),
+                publication: String::from("40615"),
+                check_digit: String::from("7"),
+            })
+        );
+
+        assert_eq!(
+            Identifier::parse("978-1-56619-909-4").isbn_parts(),
+            Some(IsbnParts {
+                prefix: String::from("978"),
+                registration_group: String::from("1"),
+                registrant: String::from("56619"),
+                publication: String::from("909"),
+                check_digit: String::from("4"),
+            })
+        );
+    }
+
+    #[test]
+    fn japanese() {
+        assert_eq!(
+            Identifier::parse("9784065199817").isbn_parts(),
+            Some(IsbnParts {
+                prefix: String::from("978"),
+                registration_group: String::from("4"),
+                registrant: String::from("06"),
+                publication: String::from("519981"),
+                check_digit: String::from("7"),
+            })
+        );
+    }
+
+    #[test]
+    fn unresolved() {
+        let examples = [
+            // Registration group not in the table.
+            "9783161484100",
+            "9791034300006",
+            // Not an ISBN.
+            "10.5555/12345678",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::parse(example).isbn_parts(),
+                None,
+                "Expected {} not to have ISBN parts",
+                example
+            );
+        }
+    }
+
+    /// The variant is public, so malformed values can be constructed, and mustn't panic.
+    #[test]
+    fn constructed() {
+        let examples = ["", "978", "9780", "97803064061", "978030640615é"];
+
+        for example in examples.iter() {
+            let identifier = Identifier::Isbn(String::from(*example));
+
+            assert_eq!(
+                identifier.isbn_parts(),
+                None,
+                "Expected {:?} not to have ISBN parts",
+                example
+            );
+            assert_eq!(
+                identifier.to_bibtex_field(),
+                Some(("isbn", String::from(*example)))
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod isbn_a_tests {
+    use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn isbn_a() {
+        let doi = Identifier::parse("https://doi.org/10.978.0306/406157");
+
+        assert_eq!(
+            doi,
+            Identifier::Doi {
+                prefix: String::from("10.978.0306"),
+                suffix: String::from("406157"),
+            },
+            "ISBN-A should still be parsed as a DOI."
+        );
+
+        assert_eq!(
+            doi.as_isbn_from_isbn_a(),
+            Some(Identifier::Isbn(String::from("9780306406157")))
+        );
+    }
+
+    #[test]
+    fn is_alias_of() {
+        let doi = Identifier::parse("https://doi.org/10.978.0306/406157");
+        let isbn = Identifier::parse("0306406152");
+
+        assert!(doi.is_alias_of(&isbn));
+        assert!(isbn.is_alias_of(&doi), "Aliases should be symmetric.");
+        assert!(doi.is_alias_of(&Identifier::parse("10.978.0306/406157")));
+
+        assert!(!doi.is_alias_of(&Identifier::parse("9781566199094")));
+        assert!(!Identifier::parse("10.5555/12345678").is_alias_of(&isbn));
+    }
+
+    #[test]
+    fn not_isbn_a() {
+        let examples = [
+            // Ordinary DOI.
+            "10.5555/12345678",
+            // Bad check digit.
+            "10.978.0306/406158",
+            // Not a DOI.
+            "9780306406157",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::parse(example).as_isbn_from_isbn_a(),
+                None,
+                "Expected {} not to be an ISBN-A",
+                example
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod isbn_equivalence_tests {
+    use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn ten_and_thirteen() {
+        let examples = [
+            ("0306406152", "9780306406157"),
+            ("0-306-40615-2", "978-0-306-40615-7"),
+            ("123456789X", "9781234567897"),
+            ("1-84356-028-3", "978-1-84356-028-9"),
+        ];
+
+        for (ten, thirteen) in examples.iter() {
+            assert_eq!(
+                Identifier::parse(ten),
+                Identifier::parse(thirteen),
+                "Expected {} and {} to be the same ISBN",
+                ten,
+                thirteen
+            );
+
+            assert!(
+                Identifier::isbn_equivalent(ten, thirteen),
+                "Expected {} and {} to be the same ISBN",
+                ten,
+                thirteen
+            );
+        }
+    }
+
+    #[test]
+    fn bookland_979() {
+        assert_eq!(
+            Identifier::parse("9791234567896"),
+            Identifier::Isbn(String::from("9791234567896"))
+        );
+
+        assert!(
+            !Identifier::isbn_equivalent("123456789X", "9791234567896"),
+            "979 ISBNs have no 10 digit form, so can't match an ISBN-10."
+        );
+    }
+
+    #[test]
+    fn not_isbn() {
+        assert!(!Identifier::isbn_equivalent("0306406152", "0306406153"));
+
+        assert!(
+            !Identifier::isbn_equivalent("hello world", "hello world"),
+            "Non-ISBNs are never ISBN equivalent, even if equal."
+        );
+
+        assert!(!Identifier::isbn_equivalent(
+            "10.5555/12345678",
+            "10.5555/12345678"
+        ));
+    }
+}
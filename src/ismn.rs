@@ -0,0 +1,132 @@
+//! ISMN, International Standard Music Number
+//! See <https://www.ismn-international.org>
+//!
+//! Identifies printed music. A 13-digit ISMN shares the EAN-13 structure with ISBNs, under the "979-0" prefix. The
+//! legacy 10-character form replaces "979-0" with "M", and has the same check digit.
+//!
+//! ISMNs are represented in the 13-digit form, with separators removed.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use crate::isbn::{generate_13_digit_checksum, SEPARATORS};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Prefix of 13 digit ISMNs, which replaces the "M" of legacy ISMNs.
+const PREFIX: &[u32] = &[9, 7, 9, 0];
+
+/// Parse an input string as an ISMN, either in 13-digit or legacy "M" form.
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let upcase = input.raw_uppercase();
+    let less_prefix = upcase.strip_prefix("ISMN").unwrap_or(&upcase).trim_start();
+
+    let digits = if let Some(rest) = less_prefix.strip_prefix('M') {
+        // Legacy form is "M" plus 9 digits.
+        let mut digits = Vec::from(PREFIX);
+        digits.extend(str_to_digits(rest).filter(|x| x.len() == 9)?);
+        digits
+    } else {
+        str_to_digits(less_prefix).filter(|x| x.len() == 13 && x.starts_with(PREFIX))?
+    };
+
+    if generate_13_digit_checksum(&digits) == digits[12] {
+        Some(Identifier::Ismn(
+            digits
+                .iter()
+                .filter_map(|x| char::from_digit(*x, 10))
+                .collect(),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Return vector of digits, ignoring the same separators as ISBNs, e.g. spaces and en dashes.
+/// If any other characters are found, including digits from other scripts, return None.
+fn str_to_digits(input: &str) -> Option<Vec<u32>> {
+    input
+        .chars()
+        .filter(|x| !SEPARATORS.contains(x))
+        .map(|x| x.to_digit(10))
+        .collect()
+}
+
+/// There's no registered URN namespace for ISMNs, so no URI.
+pub(crate) fn to_uri(_input: &Identifier) -> Option<String> {
+    None
+}
+
+/// Encode an ISMN as a stable string.
+/// Will always return a String if an ISMN type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Ismn(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod ismn_parser_tests {
+    use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn thirteen_digit() {
+        let expected = Identifier::Ismn(String::from("9790260000438"));
+
+        assert_eq!(expected, Identifier::parse("979-0-2600-0043-8"));
+        assert_eq!(expected, Identifier::parse("9790260000438"));
+        assert_eq!(expected, Identifier::parse("ISMN 979-0-2600-0043-8"));
+    }
+
+    /// The same separators are accepted as for ISBNs.
+    #[test]
+    fn typographic_separators() {
+        let expected = Identifier::Ismn(String::from("9790260000438"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("979\u{2013}0\u{2013}2600\u{2013}0043\u{2013}8")
+        );
+        assert_eq!(
+            expected,
+            Identifier::parse("M\u{00A0}2600\u{00A0}0043\u{00A0}8")
+        );
+        assert_ne!(
+            expected,
+            Identifier::parse("979-0-2600-0043-\u{0668}"),
+            "Digits from other scripts aren't accepted."
+        );
+    }
+
+    #[test]
+    fn legacy() {
+        let expected = Identifier::Ismn(String::from("9790260000438"));
+
+        assert_eq!(expected, Identifier::parse("M-2600-0043-8"));
+        assert_eq!(expected, Identifier::parse("M260000438"));
+        assert_eq!(expected, Identifier::parse("m-2600-0043-8"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn bad_check_digit() {
+        assert_eq!(
+            Identifier::Uri(String::from("979-0-2600-0043-9")),
+            Identifier::parse("979-0-2600-0043-9")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("M-2600-0043-9")),
+            Identifier::parse("M-2600-0043-9")
+        );
+    }
+
+    /// ISBNs under the rest of the 979 prefix are still ISBNs.
+    #[test]
+    fn similar_isbn() {
+        assert_eq!(
+            Identifier::Isbn(String::from("9791090636071")),
+            Identifier::parse("979-10-90636-07-1")
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! ISRC, International Standard Recording Code
+//! See <https://isrc.ifpi.org>
+//!
+//! Identifies sound and music video recordings. An ISRC is 12 characters: a two letter country code, a three character
+//! registrant code, the two digit year of reference and a five digit designation code, e.g. "US-RC1-17-00274". There's
+//! no check digit, so validation is structural only.
+//!
+//! ISRCs are represented in upper case, in the hyphenated layout.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Either all four hyphens, or none. Groups 1 to 4 are the country, registrant, year and designation.
+    static ref ISRC_RE: Regex = Regex::new(
+        r"^(?:([A-Z]{2})-([A-Z0-9]{3})-(\d{2})-(\d{5})|([A-Z]{2})([A-Z0-9]{3})(\d{2})(\d{5}))$"
+    )
+    .unwrap();
+}
+
+/// Parse an input string as an ISRC.
+///
+/// Accepts hyphenated or unhyphenated ISRCs in any case, optionally with an "ISRC" label, e.g. "US-RC1-17-00274",
+/// "USRC11700274" or "ISRC US-RC1-17-00274".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let upcase = input.raw_uppercase();
+    let less_prefix = upcase
+        .strip_prefix("ISRC")
+        .map(|rest| rest.trim_start_matches(':').trim_start())
+        .unwrap_or(&upcase);
+
+    let groups = ISRC_RE.captures(less_prefix)?;
+
+    // Only one of the alternatives matches.
+    let mut parts = groups.iter().skip(1).flatten();
+
+    Some(Identifier::Isrc(format!(
+        "{}-{}-{}-{}",
+        parts.next()?.as_str(),
+        parts.next()?.as_str(),
+        parts.next()?.as_str(),
+        parts.next()?.as_str()
+    )))
+}
+
+/// There's no registered URN namespace or resolver for ISRCs, so no URI.
+pub(crate) fn to_uri(_input: &Identifier) -> Option<String> {
+    None
+}
+
+/// Encode an ISRC as a stable string, in the hyphenated layout.
+/// Will always return a String if an ISRC type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Isrc(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod isrc_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn hyphenated() {
+        let expected = Identifier::Isrc(String::from("US-RC1-17-00274"));
+
+        assert_eq!(expected, Identifier::parse("US-RC1-17-00274"));
+        assert_eq!(expected, Identifier::parse("us-rc1-17-00274"));
+        assert_eq!(expected, Identifier::parse("ISRC US-RC1-17-00274"));
+        assert_eq!(expected, Identifier::parse("ISRC: US-RC1-17-00274"));
+    }
+
+    #[test]
+    fn unhyphenated() {
+        let expected = Identifier::Isrc(String::from("US-RC1-17-00274"));
+
+        assert_eq!(expected, Identifier::parse("USRC11700274"));
+        assert_eq!(expected, Identifier::parse("usrc11700274"));
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Designation too short.
+            "US-RC1-17-0027",
+            // Country code must be letters.
+            "U1-RC1-17-00274",
+            // Year must be digits.
+            "US-RC1-A7-00274",
+            // Hyphens in some places but not others.
+            "US-RC117-00274",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Isrc,
+                "Expected {} not to be an ISRC",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod isrc_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn no_uri() {
+        assert_eq!(None, Identifier::parse("USRC11700274").to_uri());
+    }
+}
@@ -0,0 +1,138 @@
+//! ISTC, International Standard Text Code
+//! See ISO 21047.
+//!
+//! Identifies textual works, as distinct from their editions, which have ISBNs. An ISTC is 16 hex characters: a three
+//! character registration agency, the four digit year of registration, an eight character work element and a check
+//! character, e.g. "0A9-2002-12B4A105-7".
+//!
+//! ISTCs are represented in upper case, with hyphens and spaces removed.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::string::String;
+use alloc::vec::Vec;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Weights for the check character, repeated across the first 15 characters.
+const WEIGHTS: &[u32] = &[11, 9, 3, 1];
+
+lazy_static! {
+    /// Agency, year, work element and check character, each optionally separated by a hyphen or space.
+    static ref ISTC_RE: Regex = Regex::new(r"^[0-9A-F]{3}[- ]?\d{4}[- ]?[0-9A-F]{8}[- ]?[0-9A-F]$").unwrap();
+}
+
+/// Parse an input string as an ISTC.
+///
+/// Accepts ISTCs in any case, with or without hyphens or spaces, optionally with an "ISTC" label, e.g.
+/// "0A9-2002-12B4A105-7", "0A9200212B4A1057" or "ISTC 0A9 2002 12B4A105 7".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let upcase = input.raw_uppercase();
+    let less_prefix = upcase
+        .strip_prefix("ISTC")
+        .map(|rest| rest.trim_start_matches(':').trim_start())
+        .unwrap_or(&upcase);
+
+    if !ISTC_RE.is_match(less_prefix) {
+        return None;
+    }
+
+    let value: String = less_prefix
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .collect();
+
+    if validate_check_character(&value) {
+        Some(Identifier::Istc(value))
+    } else {
+        None
+    }
+}
+
+/// Validate the check character, which is the weighted sum of the other characters, modulo 16.
+/// The input must be the 16 characters without separators.
+fn validate_check_character(value: &str) -> bool {
+    let digits: Option<Vec<u32>> = value.chars().map(|c| c.to_digit(16)).collect();
+
+    match digits {
+        Some(digits) if digits.len() == 16 => {
+            let sum: u32 = digits
+                .iter()
+                .take(15)
+                .zip(WEIGHTS.iter().cycle())
+                .map(|(digit, weight)| digit * weight)
+                .sum();
+
+            sum % 16 == digits[15]
+        }
+        _ => false,
+    }
+}
+
+/// There's no registered URN namespace or resolver for ISTCs, so no URI.
+pub(crate) fn to_uri(_input: &Identifier) -> Option<String> {
+    None
+}
+
+/// Encode an ISTC as a stable string, without separators.
+/// Will always return a String if an ISTC type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Istc(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod istc_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn valid() {
+        let expected = Identifier::Istc(String::from("0A9200212B4A1057"));
+
+        assert_eq!(expected, Identifier::parse("0A9200212B4A1057"));
+        assert_eq!(expected, Identifier::parse("0a9200212b4a1057"));
+    }
+
+    #[test]
+    fn separators() {
+        let expected = Identifier::Istc(String::from("0A9200212B4A1057"));
+
+        assert_eq!(expected, Identifier::parse("0A9-2002-12B4A105-7"));
+        assert_eq!(expected, Identifier::parse("ISTC 0A9 2002 12B4A105 7"));
+        assert_eq!(expected, Identifier::parse("ISTC: 0A9-2002-12B4A105-7"));
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Bad check character.
+            "0A9-2002-12B4A105-8",
+            // Year must be digits.
+            "0A9-20A2-12B4A105-7",
+            // Too short.
+            "0A9-2002-12B4A10-7",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Istc,
+                "Expected {} not to be an ISTC",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod istc_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn no_uri() {
+        assert_eq!(None, Identifier::parse("0A9-2002-12B4A105-7").to_uri());
+    }
+}
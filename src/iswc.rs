@@ -0,0 +1,132 @@
+//! ISWC, International Standard Musical Work Code
+//! See <https://www.iswc.org>
+//!
+//! Identifies musical works, as distinct from their recordings, which have ISRCs. An ISWC is the letter "T", nine
+//! digits and a check digit, usually written "T-034.524.680-1".
+//!
+//! ISWCs are represented in that canonical form.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Canonical or compact form, with each separator optional. Groups 1 to 4 are the three blocks of digits and the
+    /// check digit.
+    static ref ISWC_RE: Regex = Regex::new(r"^T-?(\d{3})\.?(\d{3})\.?(\d{3})-?(\d)$").unwrap();
+}
+
+/// Parse an input string as an ISWC.
+///
+/// Accepts the canonical or compact form in any case, optionally with an "ISWC" label, e.g. "T-034.524.680-1",
+/// "T0345246801" or "ISWC T-034.524.680-1".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let upcase = input.raw_uppercase();
+    let less_prefix = upcase
+        .strip_prefix("ISWC")
+        .map(|rest| rest.trim_start_matches(':').trim_start())
+        .unwrap_or(&upcase);
+
+    let groups = ISWC_RE.captures(less_prefix)?;
+    let (first, second, third, check) = (
+        groups.get(1)?.as_str(),
+        groups.get(2)?.as_str(),
+        groups.get(3)?.as_str(),
+        groups.get(4)?.as_str(),
+    );
+
+    let digits = format!("{}{}{}", first, second, third);
+
+    if check_digit(&digits)? == check.chars().next()?.to_digit(10)? {
+        Some(Identifier::Iswc(format!(
+            "T-{}.{}.{}-{}",
+            first, second, third, check
+        )))
+    } else {
+        None
+    }
+}
+
+/// Calculate the check digit for the nine digits. The "T" counts as 1, and each digit is weighted by its position.
+fn check_digit(digits: &str) -> Option<u32> {
+    let mut sum = 1;
+
+    for (index, digit) in digits.chars().enumerate() {
+        sum += (index as u32 + 1) * digit.to_digit(10)?;
+    }
+
+    Some((10 - sum % 10) % 10)
+}
+
+/// There's no registered URN namespace or resolver for ISWCs, so no URI.
+pub(crate) fn to_uri(_input: &Identifier) -> Option<String> {
+    None
+}
+
+/// Encode an ISWC as a stable string, in the canonical form.
+/// Will always return a String if an ISWC type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Iswc(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod iswc_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn canonical() {
+        let expected = Identifier::Iswc(String::from("T-034.524.680-1"));
+
+        assert_eq!(expected, Identifier::parse("T-034.524.680-1"));
+        assert_eq!(expected, Identifier::parse("t-034.524.680-1"));
+        assert_eq!(expected, Identifier::parse("ISWC T-034.524.680-1"));
+        assert_eq!(expected, Identifier::parse("ISWC: T-034.524.680-1"));
+    }
+
+    #[test]
+    fn compact() {
+        let expected = Identifier::Iswc(String::from("T-034.524.680-1"));
+
+        assert_eq!(expected, Identifier::parse("T0345246801"));
+        assert_eq!(expected, Identifier::parse("T-034524680-1"));
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Bad check digit.
+            "T-034.524.680-2",
+            "T0345246802",
+            // Wrong letter.
+            "S-034.524.680-1",
+            // Too short.
+            "T-034.524.68-1",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Iswc,
+                "Expected {} not to be an ISWC",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod iswc_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn no_uri() {
+        assert_eq!(None, Identifier::parse("T-034.524.680-1").to_uri());
+    }
+}
@@ -1,9 +1,40 @@
-//! A library of common identifier types used in Scholarly Publishing metadata. Recognises DOI, ROR, ORCID, and ISBN.
+//! A library of common identifier types used in Scholarly Publishing metadata. Recognises DOI, ROR, ORCID, ISBN, ARK, ISMN, ResearcherID, SWHID, GRID, OCLC, GND, Dimensions, Semantic Scholar, PURL, ISRC, ISTC, INSDC accession, shortDOI, ISWC, PDB and URN:NBN.
 //! Pre-release, work in progress. API subject to change but feedback welcome on the [GitHub repository](https://github.com/Pardalotus/scholarly_identifiers).
+//!
+//! The default `std` feature can be disabled for `no_std` + `alloc` environments.
+//! All identifier types except plain URIs are recognised without `std`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod accession;
+#[cfg(feature = "agency")]
+mod agency;
+mod ark;
+mod compact;
+mod diagnostics;
+mod dimensions;
 mod doi;
+mod extract;
+mod gnd;
+mod grid;
 pub mod identifiers;
 mod isbn;
+mod ismn;
+mod isrc;
+mod istc;
+mod iswc;
+mod oclc;
 mod orcid;
+mod pdb;
+mod purl;
+mod researcherid;
 mod ror;
+mod semanticscholar;
+#[cfg(feature = "serde")]
+mod serialisation;
+mod shortdoi;
+mod swhid;
 mod uri;
+mod urn_nbn;
@@ -0,0 +1,173 @@
+//! OCLC control number, as used by WorldCat
+//! See <https://www.oclc.org/bibformats/en/0xx/035.html>
+//!
+//! OCLC numbers are integers, but have been written with prefixes that depend on their length: "ocm" for 8 digits,
+//! "ocn" for 9 digits and "on" for 10 or more. In MARC records they're labelled "(OCoLC)".
+//!
+//! OCLC numbers are represented in the numeric form, without prefix or leading zeros. As a bare number is too
+//! ambiguous to parse, the stable string is the MARC form.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts of WorldCat.
+const HOSTS: &[&str] = &["worldcat.org", "www.worldcat.org"];
+
+/// Path of records on WorldCat, which precedes the OCLC number.
+const RECORD_PATH: &str = "oclc/";
+
+lazy_static! {
+    /// Number with its length-specific prefix. Group 1, 2 or 3 is the number.
+    static ref PREFIXED_RE: Regex = Regex::new(r"^(?i:ocm(\d{8})|ocn(\d{9})|on(\d{10,}))$").unwrap();
+
+    /// MARC form, with an optional prefix. Group 1 is the number.
+    static ref MARC_RE: Regex = Regex::new(r"^(?i:\(OCoLC\)\s*(?:ocm|ocn|on)?)(\d+)$").unwrap();
+
+    /// Number on its own, as found in WorldCat URLs.
+    static ref NUMBER_RE: Regex = Regex::new(r"^\d+$").unwrap();
+}
+
+/// Parse an input string as an OCLC number.
+///
+/// Accepts:
+///  - Prefixed number, e.g. "ocm12345678", "ocn123456789" or "on1234567890".
+///  - MARC form, e.g. "(OCoLC)12345678" or "(OCoLC)ocm12345678".
+///  - WorldCat URL, e.g. "https://www.worldcat.org/oclc/12345678".
+///
+/// Bare numbers aren't recognised, as they're too ambiguous.
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if let Some(groups) = PREFIXED_RE.captures(&input.raw) {
+        // Only one of the alternatives matches.
+        groups
+            .iter()
+            .skip(1)
+            .flatten()
+            .next()
+            .and_then(|number| construct(number.as_str()))
+    } else if let Some(groups) = MARC_RE.captures(&input.raw) {
+        groups.get(1).and_then(|number| construct(number.as_str()))
+    } else if HOSTS.contains(&input.host_lowercase()?.as_str()) {
+        input.path_no_slash().and_then(|path| {
+            path.strip_prefix(RECORD_PATH)
+                .filter(|number| NUMBER_RE.is_match(number))
+                .and_then(construct)
+        })
+    } else {
+        None
+    }
+}
+
+/// Construct from the digits, removing leading zeros.
+fn construct(number: &str) -> Option<Identifier> {
+    let trimmed = number.trim_start_matches('0');
+
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(Identifier::Oclc(String::from(trimmed)))
+    }
+}
+
+/// Encode an OCLC number as a URI on WorldCat.
+/// Will always return a result if an OCLC type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Oclc(value) => Some(format!("https://www.worldcat.org/oclc/{}", value)),
+        _ => None,
+    }
+}
+
+/// Encode an OCLC number as a stable string, in the MARC form, e.g. "(OCoLC)12345678".
+/// Will always return a String if an OCLC type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Oclc(value) => Some(format!("(OCoLC){}", value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod oclc_parser_tests {
+    use super::*;
+
+    #[test]
+    fn prefixes() {
+        let examples = [
+            ("ocm00012345", "12345"),
+            ("ocm12345678", "12345678"),
+            ("ocn123456789", "123456789"),
+            ("on1234567890", "1234567890"),
+            ("OCN123456789", "123456789"),
+        ];
+
+        for (input, expected) in examples.iter() {
+            assert_eq!(
+                Identifier::Oclc(String::from(*expected)),
+                Identifier::parse(input),
+                "Expected {} to be OCLC {}",
+                input,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn marc() {
+        let examples = [
+            "(OCoLC)12345678",
+            "(OCoLC)ocm12345678",
+            "(OCoLC) 12345678",
+            "(ocolc)012345678",
+        ];
+
+        for input in examples.iter() {
+            assert_eq!(
+                Identifier::Oclc(String::from("12345678")),
+                Identifier::parse(input),
+                "Expected {} to be an OCLC number",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn worldcat() {
+        assert_eq!(
+            Identifier::Oclc(String::from("12345678")),
+            Identifier::parse("https://www.worldcat.org/oclc/12345678")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_eq!(
+            Identifier::Uri(String::from("ocm1234567")),
+            Identifier::parse("ocm1234567"),
+            "Prefix for the wrong length."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("(OCoLC)")),
+            Identifier::parse("(OCoLC)"),
+            "No number."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod oclc_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://www.worldcat.org/oclc/12345678")),
+            Identifier::parse("(OCoLC)ocm12345678").to_uri()
+        );
+    }
+}
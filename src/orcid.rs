@@ -3,37 +3,145 @@
 //!
 //! Contributor ID, used to identifier authors.
 
+use crate::compact;
 use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::{String, ToString};
 use lazy_static::lazy_static;
 use regex::Regex;
 
 /// Host expressed upper case to avoid multiple case conversions.
 const HOST: &str = "orcid.org";
 
+/// The www subdomain also serves ORCID iDs.
+const WWW_HOST: &str = "www.orcid.org";
+
+/// Host of the ORCID sandbox, used for testing integrations.
+const SANDBOX_HOST: &str = "sandbox.orcid.org";
+
+/// Hosts that serve production ORCID iDs.
+const HOSTS: &[&str] = &[HOST, WWW_HOST];
+
+/// Hosts that serve sandbox ORCID iDs.
+const SANDBOX_HOSTS: &[&str] = &[SANDBOX_HOST];
+
+/// Length of a bare ORCID iD, e.g. "0000-0002-1694-233X".
+const BARE_LENGTH: usize = 19;
+
 lazy_static! {
 
     // Match an ORCID id.
     static ref ORCID_RE: Regex = Regex::new(r"^(\d{4})-(\d{4})-(\d{4})-(\d{3})([\dX])$").unwrap();
+
+    // Match an upper case ORCID id written with single spaces instead of hyphens.
+    static ref SPACED_RE: Regex = Regex::new(r"^\d{4} \d{4} \d{4} \d{3}[\dX]$").unwrap();
 }
 
 /// Parse an input string as an ORCID id.
+///
+/// Accepts:
+///  - Bare iD, e.g. "0000-0002-1694-233X", or with spaces, e.g. "0000 0002 1694 233X". The check digit makes this
+///    unambiguous.
+///  - URL, e.g. "https://orcid.org/0000-0002-1694-233X", also on "www.orcid.org".
+///  - URL without a scheme, e.g. "orcid.org/0000-0002-1694-233X".
+///  - Compact identifier, e.g. "orcid:0000-0002-1694-233X" or "https://identifiers.org/orcid:0000-0002-1694-233X".
+///
+/// If the `sandbox_orcid` option is set, sandbox URLs are also accepted, e.g.
+/// "https://sandbox.orcid.org/0000-0002-1694-233X".
 pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
-    if let Some(path) = input.path_no_slash_uppercase() {
-        match input.host_lowercase() {
-            Some(x) if x.eq(HOST) => {
-                if validate_check_digit(&path) {
-                    Some(Identifier::Orcid(path))
-                } else {
-                    None
-                }
+    try_parse_on_host(input, HOSTS)
+        .or_else(|| {
+            if input.options.sandbox_orcid {
+                try_parse_on_host(input, SANDBOX_HOSTS)
+            } else {
+                None
             }
-            _ => None,
+        })
+        .or_else(|| {
+            compact::unwrap(input, "orcid", "https://orcid.org/")
+                .and_then(|url| try_parse_on_host(&input.with_raw(&url), HOSTS))
+        })
+        .or_else(|| try_parse_bare(&input.raw))
+        .map(Identifier::Orcid)
+}
+
+/// Parse a bare ORCID iD, with hyphens or spaces. Only inputs of the right length and shape are normalised, to avoid
+/// allocating for others.
+fn try_parse_bare(raw: &str) -> Option<String> {
+    if raw.len() == BARE_LENGTH && matches!(raw.as_bytes().get(4), Some(b'-' | b' ')) {
+        normalise(raw)
+    } else {
+        None
+    }
+}
+
+/// Parse an input string as an ORCID iD on the sandbox.
+/// Returns the bare iD rather than an Identifier, so that sandbox iDs can't be confused with production ones.
+pub(crate) fn try_parse_sandbox(input: &IdentifierParseInput) -> Option<String> {
+    try_parse_on_host(input, SANDBOX_HOSTS)
+}
+
+/// Parse on any of the given hosts, with or without a scheme.
+fn try_parse_on_host(input: &IdentifierParseInput, hosts: &[&str]) -> Option<String> {
+    match input.host_lowercase() {
+        Some(x) if hosts.contains(&x.as_str()) => {
+            input.path_no_slash().and_then(|path| normalise(&path))
         }
+        _ => hosts
+            .iter()
+            .find_map(|host| input.schemeless_path(host))
+            .and_then(normalise),
+    }
+}
+
+/// Construct an ORCID from a bare ORCID iD, without a resolver.
+pub(crate) fn from_raw(raw: &str) -> Option<Identifier> {
+    normalise(raw).map(Identifier::Orcid)
+}
+
+/// Validate a bare ORCID iD, and normalise it with an upper case "X" check digit.
+/// Every form of input is normalised here, so that they're all stored the same way.
+fn normalise(candidate: &str) -> Option<String> {
+    let upcase = hyphenate(candidate);
+
+    if validate_check_digit(&upcase) {
+        Some(upcase)
     } else {
         None
     }
 }
 
+/// Upper-case a bare ORCID iD, replacing spaces with hyphens if it's written with spaces, e.g. "0000 0002 1694 233X".
+/// Only single spaces in the right places are replaced, so other separators are still rejected.
+fn hyphenate(candidate: &str) -> String {
+    let upcase = candidate.to_uppercase();
+
+    if SPACED_RE.is_match(&upcase) {
+        upcase.replace(' ', "-")
+    } else {
+        upcase
+    }
+}
+
+/// Does a bare ORCID iD have the right structure, but the wrong check digit?
+pub(crate) fn is_checksum_mismatch(candidate: &str) -> bool {
+    let upcase = hyphenate(candidate);
+
+    ORCID_RE.is_match(&upcase) && !validate_check_digit(&upcase)
+}
+
+/// If the input looks like an ORCID iD but isn't valid, explain why.
+pub(crate) fn near_miss(input: &IdentifierParseInput) -> Option<&'static str> {
+    let path = input.path_no_slash_uppercase()?;
+
+    match input.host_lowercase() {
+        Some(x) if HOSTS.contains(&x.as_str()) && is_checksum_mismatch(&path) => {
+            Some("bad check digit")
+        }
+        _ => None,
+    }
+}
+
 /// Generate check digit for ORCID ID.
 //  See <https://support.orcid.org/hc/en-us/articles/360006897674-Structure-of-the-ORCID-Identifier>
 fn generate_check_digit(base_digits: &str) -> Option<String> {
@@ -61,29 +169,30 @@ fn generate_check_digit(base_digits: &str) -> Option<String> {
 fn validate_check_digit(orcid_id: &str) -> bool {
     // Check the right length and syntax, also extract numbers and check digit.
     match ORCID_RE.captures(orcid_id) {
-        Some(groups) => {
-            // Ensure that all 4 groups, plus implicit group 0, were found.
-            // Guards the unwraps.
-            if groups.len() < 5 {
-                false
-            } else {
-                // Groups
-                let group_1 = groups.get(1).unwrap().as_str();
-                let group_2 = groups.get(2).unwrap().as_str();
-                let group_3 = groups.get(3).unwrap().as_str();
-
-                // Group 4 excludes the check digit.
-                let group_4 = groups.get(4).unwrap().as_str();
-
-                let digits = format!("{}{}{}{}", group_1, group_2, group_3, group_4);
+        // All groups are required by the regex, so are always present in a match.
+        // Group 4 excludes the check digit, which is group 5.
+        Some(groups) => match (
+            groups.get(1),
+            groups.get(2),
+            groups.get(3),
+            groups.get(4),
+            groups.get(5),
+        ) {
+            (Some(group_1), Some(group_2), Some(group_3), Some(group_4), Some(check)) => {
+                let digits = format!(
+                    "{}{}{}{}",
+                    group_1.as_str(),
+                    group_2.as_str(),
+                    group_3.as_str(),
+                    group_4.as_str()
+                );
 
                 let expected_check = generate_check_digit(&digits);
 
-                let check = groups.get(5).unwrap().as_str();
-
-                matches!(expected_check, Some(ref value) if value == check)
+                matches!(expected_check, Some(ref value) if value == check.as_str())
             }
-        }
+            _ => false,
+        },
         _ => false,
     }
 }
@@ -116,6 +225,99 @@ mod orcid_parser_tests {
         );
     }
 
+    #[test]
+    fn schemeless() {
+        assert_eq!(
+            Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            Identifier::parse("orcid.org/0000-0002-1694-233X")
+        );
+
+        assert_eq!(
+            Identifier::parse_orcid_sandbox("sandbox.orcid.org/0000-0002-1694-233X"),
+            Some(String::from("0000-0002-1694-233X"))
+        );
+    }
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::Orcid(String::from("0000-0002-1694-233X")),
+            Identifier::parse("0000-0002-1694-233x")
+        );
+
+        assert_ne!(
+            Identifier::parse("0000-0002-1694-2330").identifier_type(),
+            crate::identifiers::IdentifierType::Orcid,
+            "Check digit still applies to bare iDs."
+        );
+    }
+
+    #[test]
+    fn upper_case_check_digit() {
+        let expected = Identifier::Orcid(String::from("0000-0002-1694-233X"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://orcid.org/0000-0002-1694-233x")
+        );
+        assert_eq!(expected, Identifier::parse("orcid.org/0000-0002-1694-233x"));
+        assert_eq!(Ok(expected), Identifier::orcid("0000-0002-1694-233x"));
+
+        assert_eq!(
+            Identifier::extract_all("By 0000-0002-1694-233x."),
+            [Identifier::Orcid(String::from("0000-0002-1694-233X"))]
+        );
+
+        assert_eq!(
+            Identifier::parse_orcid_sandbox("https://sandbox.orcid.org/0000-0002-1694-233x"),
+            Some(String::from("0000-0002-1694-233X"))
+        );
+    }
+
+    #[test]
+    fn spaces() {
+        let expected = Identifier::orcid("0000-0002-1694-233X");
+
+        assert_eq!(expected, Identifier::orcid("0000 0002 1694 233X"));
+        assert_eq!(expected, Identifier::orcid("0000 0002 1694 233x"));
+        assert_eq!(
+            expected,
+            Ok(Identifier::parse("orcid.org/0000 0002 1694 233X"))
+        );
+        assert_eq!(
+            expected,
+            Ok(Identifier::parse("0000 0002 1694 233X")),
+            "Bare spaced iD parses to the same value as the hyphenated form."
+        );
+
+        let examples = [
+            // Other separators.
+            "0000_0002_1694_233X",
+            "0000.0002.1694.233X",
+            "0000\t0002\t1694\t233X",
+            // Double spaces.
+            "0000  0002  1694  233X",
+            // Mixed separators.
+            "0000 0002-1694-233X",
+            // Spaces in the wrong places.
+            "00000 002 1694 233X",
+        ];
+
+        for example in examples.iter() {
+            assert!(
+                Identifier::orcid(example).is_err(),
+                "Expected {} not to be an ORCID",
+                example
+            );
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                crate::identifiers::IdentifierType::Orcid,
+                "Expected {} not to parse as an ORCID",
+                example
+            );
+        }
+    }
+
     #[test]
     fn good_checksums() {
         assert_eq!(
@@ -131,6 +333,7 @@ mod orcid_parser_tests {
 
     /// Swapped checksums from the two examples in [`good_checksum`] to make two invalid IDs.
     #[test]
+    #[cfg(feature = "std")]
     fn bad_checksums() {
         assert_eq!(
             Identifier::Uri(String::from("https://orcid.org/0000-0002-1694-2330")),
@@ -178,9 +381,68 @@ mod orcid_parser_tests {
             "HTTP ORCID URI should parse."
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn www() {
+        let expected = Identifier::Orcid(String::from("0000-0002-1694-233X"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.orcid.org/0000-0002-1694-233X")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("www.orcid.org/0000-0002-1694-233X")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://www.orcid.org/0000-0002-1694-2330")),
+            Identifier::parse("https://www.orcid.org/0000-0002-1694-2330"),
+            "Check digit still applies on www."
+        );
+    }
 }
 
 /// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod orcid_sandbox_tests {
+    use super::*;
+
+    #[test]
+    fn sandbox() {
+        assert_eq!(
+            Identifier::parse_orcid_sandbox("https://sandbox.orcid.org/0000-0002-1694-233X"),
+            Some(String::from("0000-0002-1694-233X"))
+        );
+
+        assert_eq!(
+            Identifier::parse_orcid_sandbox("https://sandbox.orcid.org/0000-0002-1694-2330"),
+            None,
+            "Check digit is still validated."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn production_unaffected() {
+        assert_eq!(
+            Identifier::parse("https://sandbox.orcid.org/0000-0002-1694-233X"),
+            Identifier::Uri(String::from(
+                "https://sandbox.orcid.org/0000-0002-1694-233X"
+            )),
+            "Sandbox iDs are not production ORCIDs."
+        );
+
+        assert_eq!(
+            Identifier::parse_orcid_sandbox("https://orcid.org/0000-0002-1694-233X"),
+            None,
+            "Production iDs are not sandbox iDs."
+        );
+    }
+}
+
 #[cfg(test)]
 mod orcid_end_to_end_tests {
     use super::*;
@@ -0,0 +1,176 @@
+//! PDB ID, Protein Data Bank identifier
+//! See <https://www.wwpdb.org> and <https://www.rcsb.org>
+//!
+//! Identifies macromolecular structures. The classic form is four characters, a digit then three letters or digits,
+//! e.g. "1ABC". The extended form is "pdb_" and eight characters, e.g. "pdb_00001abc". Classic IDs are the same as
+//! extended IDs padded with "0000", so those are represented in the classic form, in upper case. Other extended IDs
+//! are represented in lower case.
+//!
+//! A bare classic ID is short enough to be mistaken for other things, so it's only recognised as the whole input, and
+//! must have a letter, which rules out years. URLs and extended IDs don't need a letter.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts of the RCSB Protein Data Bank.
+const HOSTS: &[&str] = &["www.rcsb.org", "rcsb.org"];
+
+/// Path of structure pages, which precedes the ID.
+const STRUCTURE_PATH: &str = "structure/";
+
+/// Prefix of extended IDs.
+const EXTENDED_PREFIX: &str = "pdb_";
+
+/// Padding of an extended ID that is the same as a classic ID.
+const CLASSIC_PADDING: &str = "0000";
+
+lazy_static! {
+    /// Classic ID, in lower case.
+    static ref CLASSIC_RE: Regex = Regex::new(r"^[1-9][a-z0-9]{3}$").unwrap();
+
+    /// Extended ID, in lower case.
+    static ref EXTENDED_RE: Regex = Regex::new(r"^pdb_[0-9]{4}[1-9][a-z0-9]{3}$").unwrap();
+}
+
+/// Parse an input string as a PDB ID.
+///
+/// Accepts:
+///  - Classic ID with at least one letter, in any case, e.g. "1ABC".
+///  - Extended ID, in any case, e.g. "pdb_00001abc".
+///  - RCSB URL, e.g. "https://www.rcsb.org/structure/1ABC".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let lowercase = input.raw_lowercase();
+
+    if CLASSIC_RE.is_match(&lowercase) {
+        if lowercase.chars().any(|c| c.is_ascii_alphabetic()) {
+            construct(&lowercase)
+        } else {
+            None
+        }
+    } else if EXTENDED_RE.is_match(&lowercase) {
+        construct(&lowercase)
+    } else if HOSTS.contains(&input.host_lowercase()?.as_str()) {
+        input
+            .path_no_slash()
+            .and_then(|path| path.strip_prefix(STRUCTURE_PATH).map(str::to_lowercase))
+            .filter(|id| CLASSIC_RE.is_match(id) || EXTENDED_RE.is_match(id))
+            .and_then(|id| construct(&id))
+    } else {
+        None
+    }
+}
+
+/// Construct from a lower case classic or extended ID, converting padded extended IDs to the classic form.
+fn construct(id: &str) -> Option<Identifier> {
+    match id.strip_prefix(EXTENDED_PREFIX) {
+        Some(rest) => match rest.strip_prefix(CLASSIC_PADDING) {
+            Some(classic) => Some(Identifier::Pdb(classic.to_uppercase())),
+            None => Some(Identifier::Pdb(String::from(id))),
+        },
+        None => Some(Identifier::Pdb(id.to_uppercase())),
+    }
+}
+
+/// Encode a PDB ID as a URI on RCSB.
+/// Will always return a result if a PDB type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Pdb(value) => Some(format!("https://www.rcsb.org/{}{}", STRUCTURE_PATH, value)),
+        _ => None,
+    }
+}
+
+/// Encode a PDB ID as a stable string, which is the URI, as a bare classic ID without a letter isn't recognised.
+/// Will always return a String if a PDB type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    to_uri(input)
+}
+
+#[cfg(test)]
+mod pdb_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn classic() {
+        let expected = Identifier::Pdb(String::from("1ABC"));
+
+        assert_eq!(expected, Identifier::parse("1ABC"));
+        assert_eq!(expected, Identifier::parse("1abc"));
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.rcsb.org/structure/1abc")
+        );
+        assert_eq!(
+            expected,
+            Identifier::parse("https://rcsb.org/structure/1ABC")
+        );
+    }
+
+    #[test]
+    fn extended() {
+        assert_eq!(
+            Identifier::Pdb(String::from("1ABC")),
+            Identifier::parse("pdb_00001abc"),
+            "Padded extended ID is the same as the classic ID."
+        );
+
+        assert_eq!(
+            Identifier::Pdb(String::from("pdb_10001abc")),
+            Identifier::parse("PDB_10001ABC")
+        );
+
+        assert_eq!(
+            Identifier::Pdb(String::from("1234")),
+            Identifier::parse("https://www.rcsb.org/structure/1234"),
+            "IDs without letters are recognised in URLs."
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Words.
+            "ABCD",
+            "word",
+            // Years.
+            "2020",
+            // First character can't be zero.
+            "0ABC",
+            "1ABCD",
+            "pdb_0001abc",
+            "https://www.rcsb.org/sequence/1ABC",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::Pdb,
+                "Expected {} not to be a PDB ID",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod pdb_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://www.rcsb.org/structure/1ABC")),
+            Identifier::parse("pdb_00001abc").to_uri()
+        );
+
+        assert_eq!(
+            Some(String::from("https://www.rcsb.org/structure/pdb_10001abc")),
+            Identifier::parse("pdb_10001abc").to_uri()
+        );
+    }
+}
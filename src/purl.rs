@@ -0,0 +1,122 @@
+//! PURL, Persistent Uniform Resource Locator
+//! See <https://purl.archive.org>
+//!
+//! PURLs are HTTP URLs on a redirecting service, e.g. "http://purl.org/dc/terms/title", and are used throughout RDF and
+//! Dublin Core metadata. They're URLs, so the value is the whole URL. The path is case-sensitive, so it's preserved.
+//! The scheme is normalised to https, as PURLs are equivalent over either.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+
+/// Hosts of PURL services.
+const HOSTS: &[&str] = &["purl.org", "www.purl.org", "purl.oclc.org"];
+
+/// Parse an input string as a PURL.
+///
+/// Accepts an http or https URL on a PURL host, with a path, e.g. "http://purl.org/dc/terms/title".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let lowercase = input.raw_lowercase();
+    if !lowercase.starts_with("http://") && !lowercase.starts_with("https://") {
+        return None;
+    }
+
+    let host = input.host_lowercase()?;
+    if !HOSTS.contains(&host.as_str()) {
+        return None;
+    }
+
+    // The service's own home page isn't a PURL.
+    let path = input.path_no_slash().filter(|path| !path.is_empty())?;
+
+    Some(Identifier::Purl(match input.query() {
+        Some(query) => format!("https://{}/{}?{}", host, path, query),
+        None => format!("https://{}/{}", host, path),
+    }))
+}
+
+/// Encode a PURL as a URI, which is the stored URL.
+/// Will always return a result if a PURL type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Purl(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+/// Encode a PURL as a stable string, which is the URL.
+/// Will always return a String if a PURL type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    to_uri(input)
+}
+
+#[cfg(test)]
+mod purl_parser_tests {
+    use super::*;
+
+    #[test]
+    fn purl() {
+        let expected = Identifier::Purl(String::from("https://purl.org/dc/terms/title"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://purl.org/dc/terms/title")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://purl.org/dc/terms/title")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("HTTP://PURL.ORG/dc/terms/title"),
+            "Scheme and host are case-insensitive."
+        );
+
+        assert_eq!(
+            Identifier::Purl(String::from("https://purl.oclc.org/NET/Example")),
+            Identifier::parse("http://purl.oclc.org/NET/Example"),
+            "Case of the path should be preserved."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn not_purl() {
+        let examples = [
+            "http://example.com/dc/terms/title",
+            "http://purl.org/",
+            "ftp://purl.org/dc/terms/title",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                crate::identifiers::IdentifierType::Purl,
+                "Expected {} not to be a PURL",
+                example
+            );
+        }
+
+        assert_eq!(
+            Identifier::Uri(String::from("http://example.com/dc/terms/title")),
+            Identifier::parse("http://example.com/dc/terms/title"),
+            "Other URLs are still URIs."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod purl_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://purl.org/dc/terms/title")),
+            Identifier::parse("http://purl.org/dc/terms/title").to_uri()
+        );
+    }
+}
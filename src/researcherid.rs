@@ -0,0 +1,160 @@
+//! ResearcherID, the Web of Science (formerly Publons) author identifier
+//! See <https://www.webofscience.com>
+//!
+//! A ResearcherID is one to three letters, four digits, and the four digit year it was registered, e.g.
+//! "A-1234-2010" or "AAA-1234-2020". They were originally resolved on "researcherid.com", and are now resolved on Web
+//! of Science.
+//!
+//! The bare form is short and could be mistaken for other things, so it's only recognised when it exactly fits the
+//! pattern.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Current resolver, on Web of Science.
+const RESOLVER: &str = "https://www.webofscience.com/wos/author/record/";
+
+/// Hosts with the path prefix that precedes the ResearcherID.
+const HOSTS: &[(&str, &str)] = &[
+    ("researcherid.com", "rid/"),
+    ("www.researcherid.com", "rid/"),
+    ("webofscience.com", "wos/author/record/"),
+    ("www.webofscience.com", "wos/author/record/"),
+];
+
+lazy_static! {
+    /// Letters, four digits, then four digit year.
+    static ref RESEARCHERID_RE: Regex = Regex::new(r"^[A-Z]{1,3}-\d{4}-(19|20)\d{2}$").unwrap();
+}
+
+/// Parse an input string as a ResearcherID.
+///
+/// Accepts:
+///  - Bare ResearcherID in upper case, e.g. "A-1234-2010".
+///  - Legacy URL, e.g. "http://www.researcherid.com/rid/A-1234-2010".
+///  - Web of Science URL, e.g. "https://www.webofscience.com/wos/author/record/A-1234-2010".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if RESEARCHERID_RE.is_match(&input.raw) {
+        Some(Identifier::ResearcherId(String::from(input.raw.as_ref())))
+    } else {
+        let host = input.host_lowercase()?;
+        let path = input.path_no_slash_uppercase()?;
+
+        HOSTS
+            .iter()
+            .filter(|(candidate, _)| host.eq(candidate))
+            .find_map(|(_, prefix)| path.strip_prefix(&prefix.to_uppercase()))
+            .filter(|value| RESEARCHERID_RE.is_match(value))
+            .map(|value| Identifier::ResearcherId(String::from(value)))
+    }
+}
+
+/// Encode a ResearcherID as a URI on Web of Science.
+/// Will always return a result if a ResearcherID type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::ResearcherId(value) => Some(format!("{}{}", RESOLVER, value)),
+        _ => None,
+    }
+}
+
+/// Encode a ResearcherID as a stable string, without a resolver.
+/// Will always return a String if a ResearcherID type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::ResearcherId(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod researcherid_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            Identifier::ResearcherId(String::from("A-1234-2010")),
+            Identifier::parse("A-1234-2010")
+        );
+
+        assert_eq!(
+            Identifier::ResearcherId(String::from("AAA-1234-2020")),
+            Identifier::parse("AAA-1234-2020"),
+            "Newer three letter form."
+        );
+    }
+
+    #[test]
+    fn resolver() {
+        let expected = Identifier::ResearcherId(String::from("A-1234-2010"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://www.researcherid.com/rid/A-1234-2010"),
+            "Legacy researcherid.com URL."
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://www.webofscience.com/wos/author/record/A-1234-2010"),
+            "Web of Science URL."
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("http://www.researcherid.com/rid/a-1234-2010"),
+            "Normalised to upper case when on a resolver."
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // Lower case bare form is too ambiguous.
+            "a-1234-2010",
+            // Too many letters.
+            "AAAA-1234-2010",
+            // Too few digits.
+            "A-123-2010",
+            // Not a year.
+            "A-1234-0010",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                IdentifierType::ResearcherId,
+                Identifier::parse(example).identifier_type(),
+                "Expected {} not to be a ResearcherID",
+                example
+            );
+        }
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com/rid/A-1234-2010")),
+            Identifier::parse("https://example.com/rid/A-1234-2010"),
+            "Other hosts are not recognised."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod researcherid_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://www.webofscience.com/wos/author/record/A-1234-2010"
+            )),
+            Identifier::parse("http://www.researcherid.com/rid/A-1234-2010").to_uri()
+        );
+    }
+}
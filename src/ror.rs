@@ -1,20 +1,32 @@
 //! ROR, Research Organisation Registry identifier
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
 
+use crate::compact;
 use crate::identifiers::{Identifier, IdentifierParseInput};
 use lazy_static::lazy_static;
 use regex::Regex;
 
 const HOST: &str = "ror.org";
 
+/// The www subdomain also serves ROR IDs.
+const WWW_HOST: &str = "www.ror.org";
+
+/// Host of the ROR API.
+const API_HOST: &str = "api.ror.org";
+
+/// Paths of organisation records on the API, which precede the ROR ID or the whole ROR URL.
+const API_PATHS: &[&str] = &["v1/organizations/", "v2/organizations/", "organizations/"];
+
 lazy_static! {
     /// Group 1 is the identifier, group 2 is the checksum digit.
-    static ref PATH_RE: Regex = Regex::new(r"^(0[a-hj-km-np-tv-z|0-9]{6})([0-9]{2})$").unwrap();
+    static ref PATH_RE: Regex = Regex::new(r"^(0[a-hj-km-np-tv-z0-9]{6})([0-9]{2})$").unwrap();
 
     /// With reference to Douglas Crockford's Base32 implementation.
     /// See <https://www.crockford.com/base32.html>.
-    static ref BASE_32_DECODE: HashMap<char, u64> = "0123456789abcdefghjkmnpqrstvwxyz"
+    static ref BASE_32_DECODE: BTreeMap<char, u64> = "0123456789abcdefghjkmnpqrstvwxyz"
         .chars()
         .enumerate()
         .map(|(i, c)| (c, i as u64 ))
@@ -23,37 +35,77 @@ lazy_static! {
 }
 
 /// Parse an input string as a ROR id.
+///
+/// Accepts:
+///  - URL, e.g. "https://ror.org/02twcfp32", also on "www.ror.org".
+///  - URL without a scheme, e.g. "ror.org/02twcfp32".
+///  - API URL, with the ROR ID or the whole ROR URL, e.g. "https://api.ror.org/v2/organizations/02twcfp32" or
+///    "https://api.ror.org/v2/organizations/https://ror.org/02twcfp32".
+///  - Compact identifier, e.g. "ror:02twcfp32" or "https://identifiers.org/ror:02twcfp32".
+///
+/// Any query string or fragment is ignored.
 pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
-    if let Some(host) = input.host_lowercase() {
-        if host.eq(HOST) {
-            if let Some(path) = input.path_no_slash() {
-                if validate_check_digit(&path) {
-                    Some(Identifier::Ror(path))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        } else {
-            None
+    try_parse_url(input).or_else(|| {
+        compact::unwrap(input, "ror", "https://ror.org/")
+            .and_then(|url| try_parse_url(&input.with_raw(&url)))
+    })
+}
+
+/// Parse an input string as a ROR ID in any of its URL forms.
+fn try_parse_url(input: &IdentifierParseInput) -> Option<Identifier> {
+    let path = match input.host_lowercase() {
+        Some(host) if host.eq(HOST) || host.eq(WWW_HOST) => input.path_no_slash(),
+        Some(host) if host.eq(API_HOST) => {
+            input.path_no_slash().and_then(|path| from_api_path(&path))
         }
+        _ => input
+            .schemeless_path(HOST)
+            .or_else(|| input.schemeless_path(WWW_HOST))
+            .and_then(|path| path.split(['?', '#']).next())
+            .map(String::from),
+    }?;
+
+    if validate_check_digit(&path) {
+        Some(Identifier::Ror(path))
     } else {
         None
     }
 }
 
+/// Find the ROR ID in the path of an API URL. It's the last segment, whether or not the whole ROR URL is embedded.
+fn from_api_path(path: &str) -> Option<String> {
+    API_PATHS
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix))
+        .and_then(|rest| rest.rsplit('/').next())
+        .map(String::from)
+}
+
+/// If the input looks like a ROR ID but isn't valid, explain why.
+pub(crate) fn near_miss(input: &IdentifierParseInput) -> Option<&'static str> {
+    let path = input.path_no_slash()?;
+
+    match input.host_lowercase() {
+        Some(host)
+            if (host.eq(HOST) || host.eq(WWW_HOST))
+                && PATH_RE.is_match(&path)
+                && !validate_check_digit(&path) =>
+        {
+            Some("bad check digit")
+        }
+        _ => None,
+    }
+}
+
 fn validate_check_digit(path: &str) -> bool {
-    match PATH_RE.captures(path) {
-        // Only accept the two groups (plus implicit group).
-        Some(groups) if groups.len() != 3 => false,
-        Some(groups) => {
-            // Groups
-            let identifier = groups.get(1).unwrap().as_str();
-            let check_digit = groups.get(2).unwrap().as_str();
-
-            if let Ok(check_digit_value) = check_digit.parse::<u64>() {
-                expected_check_digit(identifier) == check_digit_value
+    // Both groups are required by the regex, so are always present in a match.
+    match PATH_RE
+        .captures(path)
+        .map(|groups| (groups.get(1), groups.get(2)))
+    {
+        Some((Some(identifier), Some(check_digit))) => {
+            if let Ok(check_digit_value) = check_digit.as_str().parse::<u64>() {
+                expected_check_digit(identifier.as_str()) == check_digit_value
             } else {
                 false
             }
@@ -91,6 +143,21 @@ pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
 #[cfg(test)]
 mod ror_parser_tests {
     use crate::identifiers::Identifier;
+    use alloc::string::String;
+
+    #[test]
+    fn schemeless() {
+        assert_eq!(
+            Identifier::Ror(String::from("02mhbdp94")),
+            Identifier::parse("ror.org/02mhbdp94")
+        );
+
+        assert_eq!(
+            Identifier::Ror(String::from("02mhbdp94")),
+            Identifier::parse("ROR.org/02mhbdp94"),
+            "Host is case-insensitive."
+        );
+    }
 
     #[test]
     fn simple() {
@@ -117,6 +184,22 @@ mod ror_parser_tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
+    fn www() {
+        let expected = Identifier::Ror(String::from("02mhbdp94"));
+
+        assert_eq!(expected, Identifier::parse("https://www.ror.org/02mhbdp94"));
+        assert_eq!(expected, Identifier::parse("www.ror.org/02mhbdp94"));
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://www.ror.org/02mhbdp95")),
+            Identifier::parse("https://www.ror.org/02mhbdp95"),
+            "Check digit still applies on www."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn case_sensitive() {
         // 1-character upcase from previous examples.
         assert_eq!(
@@ -133,6 +216,7 @@ mod ror_parser_tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn checksum() {
         // Good example 1.
         assert_eq!(
@@ -173,4 +257,77 @@ mod ror_parser_tests {
             "Simple ROR URI accepted"
         );
     }
+
+    #[test]
+    fn query() {
+        let expected = Identifier::Ror(String::from("02mhbdp94"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://ror.org/02mhbdp94?utm_source=example")
+        );
+
+        assert_eq!(expected, Identifier::parse("ror.org/02mhbdp94?tab=details"));
+        assert_eq!(expected, Identifier::parse("https://ror.org/02mhbdp94#top"));
+    }
+
+    #[test]
+    fn authority() {
+        let expected = Identifier::Ror(String::from("02twcfp32"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://ror.org:443/02twcfp32"),
+            "Port is ignored."
+        );
+        assert_eq!(
+            expected,
+            Identifier::parse("https://user@ror.org/02twcfp32"),
+            "Userinfo is ignored."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn api() {
+        let expected = Identifier::Ror(String::from("02mhbdp94"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://api.ror.org/v2/organizations/https://ror.org/02mhbdp94")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://api.ror.org/v2/organizations/02mhbdp94")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://api.ror.org/organizations/02mhbdp94")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "https://api.ror.org/v2/organizations/02mhbdp99"
+            )),
+            Identifier::parse("https://api.ror.org/v2/organizations/02mhbdp99"),
+            "Check digit is still validated."
+        );
+    }
+
+    #[test]
+    fn pipe() {
+        // The check digit for "00twcfp" is 20, so would match if a pipe were allowed and counted as zero.
+        assert_eq!(
+            Identifier::Ror(String::from("00twcfp20")),
+            Identifier::parse("ror.org/00twcfp20")
+        );
+
+        assert_ne!(
+            Identifier::parse("ror.org/0|twcfp20").identifier_type(),
+            crate::identifiers::IdentifierType::Ror,
+            "Pipe isn't in the base32 alphabet."
+        );
+    }
 }
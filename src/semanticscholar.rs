@@ -0,0 +1,197 @@
+//! Semantic Scholar paper identifiers
+//! See <https://www.semanticscholar.org/product/api>
+//!
+//! Semantic Scholar identifies papers in two ways: the Corpus ID, a number written with a prefix, e.g.
+//! "CorpusID:12345678", and the paper ID, a 40 hex digit SHA, e.g. "649def34f8be52c8b66281af98ae884c09aef38b".
+//!
+//! Both are represented as strings in their bare form. Corpus IDs keep their "CorpusID:" prefix, which distinguishes
+//! them from paper IDs. A bare number is too ambiguous, so the prefix is required.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts of the Semantic Scholar website.
+const HOSTS: &[&str] = &["www.semanticscholar.org", "semanticscholar.org"];
+
+/// Host of the Semantic Scholar API, which redirects Corpus IDs to paper pages.
+const API_HOST: &str = "api.semanticscholar.org";
+
+/// Path of paper pages, which precedes an optional title slug and the paper ID.
+const PAPER_PATH: &str = "paper/";
+
+/// Prefix for Corpus IDs, in its canonical case.
+const CORPUS_ID_PREFIX: &str = "CorpusID:";
+
+lazy_static! {
+    /// Corpus ID with its prefix, in any case. Group 1 is the number.
+    static ref CORPUS_ID_RE: Regex = Regex::new(r"^(?i:CorpusID):(\d+)$").unwrap();
+
+    /// Paper ID, which is 40 hex digits.
+    static ref PAPER_ID_RE: Regex = Regex::new(r"^[0-9a-f]{40}$").unwrap();
+}
+
+/// Parse an input string as a Semantic Scholar ID.
+///
+/// Accepts:
+///  - Corpus ID, e.g. "CorpusID:12345678".
+///  - Paper ID in lower case, e.g. "649def34f8be52c8b66281af98ae884c09aef38b".
+///  - Paper URL, with or without the title slug, e.g.
+///    "https://www.semanticscholar.org/paper/Title-Slug/649def34f8be52c8b66281af98ae884c09aef38b".
+///  - Any of the above on the API, e.g. "https://api.semanticscholar.org/CorpusID:12345678".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if let Some(result) = construct(&input.raw) {
+        Some(result)
+    } else {
+        let host = input.host_lowercase()?;
+        let path = input.path_no_slash()?;
+
+        let id = if HOSTS.contains(&host.as_str()) {
+            // The paper ID is the last segment, after any title slug.
+            path.strip_prefix(PAPER_PATH)?.rsplit('/').next()?
+        } else if host.eq(API_HOST) {
+            &path
+        } else {
+            return None;
+        };
+
+        construct(&id.to_lowercase())
+    }
+}
+
+/// Construct from a bare Corpus ID or paper ID.
+fn construct(input: &str) -> Option<Identifier> {
+    if let Some(groups) = CORPUS_ID_RE.captures(input) {
+        groups.get(1).map(|number| {
+            Identifier::SemanticScholar(format!("{}{}", CORPUS_ID_PREFIX, number.as_str()))
+        })
+    } else if PAPER_ID_RE.is_match(input) {
+        Some(Identifier::SemanticScholar(String::from(input)))
+    } else {
+        None
+    }
+}
+
+/// Encode a Semantic Scholar ID as a URI.
+/// Paper IDs link to the paper page. Corpus IDs don't have a page of their own, so they link to the API, which redirects
+/// to the paper page.
+/// Will always return a result if a Semantic Scholar type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::SemanticScholar(value) if value.starts_with(CORPUS_ID_PREFIX) => {
+            Some(format!("https://{}/{}", API_HOST, value))
+        }
+        Identifier::SemanticScholar(value) => Some(format!(
+            "https://www.semanticscholar.org/{}{}",
+            PAPER_PATH, value
+        )),
+        _ => None,
+    }
+}
+
+/// Encode a Semantic Scholar ID as a stable string, in its bare form.
+/// Will always return a String if a Semantic Scholar type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::SemanticScholar(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod semanticscholar_parser_tests {
+    use super::*;
+
+    #[test]
+    fn corpus_id() {
+        let expected = Identifier::SemanticScholar(String::from("CorpusID:12345678"));
+
+        assert_eq!(expected, Identifier::parse("CorpusID:12345678"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("corpusid:12345678"),
+            "Prefix should be normalised."
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse("https://api.semanticscholar.org/CorpusID:12345678")
+        );
+    }
+
+    #[test]
+    fn paper_id() {
+        let expected =
+            Identifier::SemanticScholar(String::from("649def34f8be52c8b66281af98ae884c09aef38b"));
+
+        assert_eq!(
+            expected,
+            Identifier::parse("649def34f8be52c8b66281af98ae884c09aef38b")
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse(
+                "https://www.semanticscholar.org/paper/649def34f8be52c8b66281af98ae884c09aef38b"
+            )
+        );
+
+        assert_eq!(
+            expected,
+            Identifier::parse(
+                "https://www.semanticscholar.org/paper/Construction-of-the-Literature-Graph-Ammar-Groeneveld/649def34f8be52c8b66281af98ae884c09aef38b"
+            ),
+            "Title slug should be ignored."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid() {
+        assert_ne!(
+            Identifier::parse("12345678").identifier_type(),
+            crate::identifiers::IdentifierType::SemanticScholar,
+            "Bare numbers are too ambiguous."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "https://www.semanticscholar.org/author/12345678"
+            )),
+            Identifier::parse("https://www.semanticscholar.org/author/12345678"),
+            "Only paper pages are recognised."
+        );
+
+        assert_ne!(
+            Identifier::parse("649def34f8be52c8b66281af98ae884c09aef38").identifier_type(),
+            crate::identifiers::IdentifierType::SemanticScholar,
+            "Paper ID too short."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod semanticscholar_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://www.semanticscholar.org/paper/649def34f8be52c8b66281af98ae884c09aef38b"
+            )),
+            Identifier::parse("649def34f8be52c8b66281af98ae884c09aef38b").to_uri()
+        );
+
+        assert_eq!(
+            Some(String::from(
+                "https://api.semanticscholar.org/CorpusID:12345678"
+            )),
+            Identifier::parse("CorpusID:12345678").to_uri()
+        );
+    }
+}
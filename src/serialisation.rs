@@ -0,0 +1,132 @@
+//! Serialisation of Identifiers with serde
+//!
+//! Identifiers are serialised as a typed object, with the type name and the stable string, e.g.
+//! `{"type": "doi", "value": "https://doi.org/10.5555/12345678"}`. This is the form used by many JSON APIs, and says
+//! which type the value is without parsing it.
+//!
+//! Both the typed object and a bare string are deserialised. A bare string is parsed as [`Identifier::parse`] would.
+//! A typed object is constructed as [`Identifier::from_id_string_pair`] would, so a value that isn't valid for the
+//! type is an error rather than falling through to another type.
+
+use crate::identifiers::{Identifier, IdentifierType};
+use alloc::format;
+use alloc::string::String;
+use serde::de::Error;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Either representation of an Identifier.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Representation {
+    Bare(String),
+    Typed {
+        #[serde(rename = "type")]
+        type_name: String,
+        value: String,
+    },
+}
+
+impl Serialize for Identifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Identifier", 2)?;
+        state.serialize_field("type", self.identifier_type().name())?;
+        state.serialize_field("value", &self.to_stable_string())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Identifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Representation::deserialize(deserializer)? {
+            Representation::Bare(value) => Ok(Identifier::parse_owned(value)),
+            Representation::Typed { type_name, value } => {
+                let identifier_type = IdentifierType::all()
+                    .iter()
+                    .find(|identifier_type| identifier_type.name() == type_name)
+                    .ok_or_else(|| {
+                        D::Error::custom(format!("unknown identifier type {}", type_name))
+                    })?;
+
+                Identifier::try_from((value, identifier_type.id())).map_err(D::Error::custom)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod serialisation_tests {
+    use crate::identifiers::Identifier;
+
+    #[test]
+    fn serialise_typed() {
+        assert_eq!(
+            serde_json::to_string(&Identifier::parse("10.5555/12345678")).unwrap(),
+            r#"{"type":"doi","value":"https://doi.org/10.5555/12345678"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Identifier::parse("0306406152")).unwrap(),
+            r#"{"type":"isbn","value":"9780306406157"}"#
+        );
+    }
+
+    #[test]
+    fn deserialise_both_forms() {
+        let expected = Identifier::Doi {
+            prefix: String::from("10.5555"),
+            suffix: String::from("12345678"),
+        };
+
+        let bare: Identifier =
+            serde_json::from_str(r#""https://doi.org/10.5555/12345678""#).unwrap();
+        let typed: Identifier =
+            serde_json::from_str(r#"{"type": "doi", "value": "10.5555/12345678"}"#).unwrap();
+
+        assert_eq!(bare, expected);
+        assert_eq!(typed, expected);
+    }
+
+    #[test]
+    fn round_trip() {
+        let examples = [
+            "10.5555/12345678",
+            "https://orcid.org/0000-0002-1694-233X",
+            "https://ror.org/02twcfp32",
+            "0306406152",
+            "https://example.com/path",
+            "hello world",
+        ];
+
+        for example in examples.iter() {
+            let identifier = Identifier::parse(example);
+            let json = serde_json::to_string(&identifier).unwrap();
+
+            assert_eq!(
+                serde_json::from_str::<Identifier>(&json).unwrap(),
+                identifier,
+                "Expected {} to round-trip through JSON",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_typed() {
+        let examples = [
+            // Unknown type.
+            r#"{"type": "pubmed", "value": "12345678"}"#,
+            // Value isn't valid for the type.
+            r#"{"type": "orcid", "value": "https://orcid.org/0000-0002-1694-2330"}"#,
+            r#"{"type": "doi", "value": "0306406152"}"#,
+        ];
+
+        for example in examples.iter() {
+            assert!(
+                serde_json::from_str::<Identifier>(example).is_err(),
+                "Expected {} not to deserialise",
+                example
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! shortDOI, an alias for a DOI
+//! See <https://shortdoi.org>
+//!
+//! A shortDOI is a short name for an existing DOI, under the "10" handle prefix rather than a "10." DOI prefix, e.g.
+//! "10/abcde". It isn't a full DOI, and the DOI it's an alias for can't be found without resolving it, so it's a type
+//! of its own.
+//!
+//! shortDOIs are case-insensitive, so they're represented in lower case.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Hosts that resolve shortDOIs.
+const HOSTS: &[&str] = &["doi.org", "dx.doi.org", "www.doi.org", "shortdoi.org"];
+
+lazy_static! {
+    /// The "10" handle prefix, with no dot, and an alphanumeric name.
+    static ref SHORTDOI_RE: Regex = Regex::new(r"^10/[a-z0-9]+$").unwrap();
+
+    /// Name with at least one letter. A bare "10/" followed by digits is more likely to be a date or a fraction.
+    static ref HAS_LETTER_RE: Regex = Regex::new(r"[a-z]").unwrap();
+}
+
+/// Parse an input string as a shortDOI.
+///
+/// Accepts:
+///  - URL, e.g. "https://doi.org/10/abcde" or "https://shortdoi.org/10/abcde".
+///  - Bare shortDOI, e.g. "10/abcde", as long as the name has a letter.
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    let lowercase = input.raw_lowercase();
+
+    if SHORTDOI_RE.is_match(&lowercase) {
+        if HAS_LETTER_RE.is_match(&lowercase) {
+            Some(Identifier::ShortDoi(lowercase.into_owned()))
+        } else {
+            None
+        }
+    } else if HOSTS.contains(&input.host_lowercase()?.as_str()) {
+        input
+            .path_no_slash()
+            .map(|path| path.to_lowercase())
+            .filter(|path| SHORTDOI_RE.is_match(path))
+            .map(Identifier::ShortDoi)
+    } else {
+        None
+    }
+}
+
+/// Encode a shortDOI as a URI on the DOI resolver.
+/// Will always return a result if a shortDOI type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::ShortDoi(value) => Some(format!("https://doi.org/{}", value)),
+        _ => None,
+    }
+}
+
+/// Encode a shortDOI as a stable string, as the URI, as a bare shortDOI with no letters wouldn't be recognised.
+/// Will always return a String if a shortDOI type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    to_uri(input)
+}
+
+#[cfg(test)]
+mod shortdoi_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn shortdoi() {
+        let expected = Identifier::ShortDoi(String::from("10/abcde"));
+
+        let examples = [
+            "10/abcde",
+            "10/ABCDE",
+            "https://doi.org/10/abcde",
+            "http://dx.doi.org/10/abcde",
+            "https://shortdoi.org/10/abcde",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                expected,
+                Identifier::parse(example),
+                "Expected {} to be a shortDOI",
+                example
+            );
+        }
+
+        assert_eq!(
+            Identifier::ShortDoi(String::from("10/12345")),
+            Identifier::parse("https://doi.org/10/12345"),
+            "Names without letters are recognised in URLs."
+        );
+    }
+
+    #[test]
+    fn full_doi() {
+        for example in ["10.5555/abcde", "https://doi.org/10.5555/abcde"] {
+            assert_eq!(
+                Identifier::Doi {
+                    prefix: String::from("10.5555"),
+                    suffix: String::from("abcde")
+                },
+                Identifier::parse(example),
+                "Expected {} to be a full DOI",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn not_shortdoi() {
+        let examples = [
+            // Probably a date.
+            "10/2020",
+            "10/abc/de",
+            "10/abc-de",
+            "11/abcde",
+            "https://example.com/10/abcde",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::ShortDoi,
+                "Expected {} not to be a shortDOI",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod shortdoi_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from("https://doi.org/10/abcde")),
+            Identifier::parse("10/ABCDE").to_uri()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! SWHID, Software Heritage persistent identifier
+//! See <https://www.swhid.org/specification/v1.1/>
+//!
+//! Identifies source code archived by Software Heritage, e.g. "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d".
+//! The core identifier is the scheme version, the object type and the SHA1 hash of the object. It may be followed by
+//! qualifiers, e.g. ";origin=https://github.com/example/example", which are preserved.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// The Software Heritage archive, which resolves SWHIDs.
+const RESOLVER_HOST: &str = "archive.softwareheritage.org";
+
+lazy_static! {
+    /// Core identifier, with object type and 40 hex digit hash, and optional qualifiers.
+    /// Object types are content, directory, revision, release and snapshot.
+    static ref SWHID_RE: Regex = Regex::new(r"^swh:1:(cnt|dir|rev|rel|snp):[0-9a-f]{40}(;.*)?$").unwrap();
+}
+
+/// Parse an input string as a SWHID.
+///
+/// Accepts:
+///  - Bare SWHID, e.g. "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d", with optional qualifiers.
+///  - SWHID on the Software Heritage archive, e.g. "https://archive.softwareheritage.org/swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    if SWHID_RE.is_match(&input.raw) {
+        Some(Identifier::Swhid(String::from(input.raw.as_ref())))
+    } else if input.host_lowercase()?.eq(RESOLVER_HOST) {
+        input
+            .path_no_slash()
+            .filter(|path| SWHID_RE.is_match(path))
+            .map(Identifier::Swhid)
+    } else {
+        None
+    }
+}
+
+/// Encode a SWHID as a URI on the Software Heritage archive.
+/// Will always return a result if a SWHID type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Swhid(value) => Some(format!("https://{}/{}", RESOLVER_HOST, value)),
+        _ => None,
+    }
+}
+
+/// Encode a SWHID as a stable string, without the resolver.
+/// Will always return a String if a SWHID type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::Swhid(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod swhid_parser_tests {
+    use super::*;
+
+    #[test]
+    fn object_types() {
+        let examples = [
+            "swh:1:cnt:94a9ed024d3859793618152ea559a168bbcbb5e2",
+            "swh:1:dir:d198bc9d7a6bcf6db04f476d29314f157507d505",
+            "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d",
+            "swh:1:rel:22ece559cc7cc2364edc5e5593d63ae8bd229f9f",
+            "swh:1:snp:c7c108084bc0bf3d81436bf980b46e98bd338453",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::Swhid(String::from(*example)),
+                Identifier::parse(example),
+                "Expected {} to be a SWHID",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn qualifiers() {
+        let input = "swh:1:cnt:4d99d2d18326621ccdd70f5ea66c2e2ac236ad8b;origin=https://gitorious.org/ocamlp3l/ocamlp3l_cvs.git;lines=9-15";
+
+        assert_eq!(
+            Identifier::Swhid(String::from(input)),
+            Identifier::parse(input),
+            "Qualifiers should be preserved."
+        );
+    }
+
+    #[test]
+    fn resolver() {
+        assert_eq!(
+            Identifier::Swhid(String::from(
+                "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d"
+            )),
+            Identifier::parse(
+                "https://archive.softwareheritage.org/swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d"
+            )
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Identifier::String(String::from(
+                "swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f"
+            )),
+            Identifier::parse("swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f"),
+            "Hash too short."
+        );
+
+        assert_eq!(
+            Identifier::String(String::from(
+                "swh:1:xyz:309cf2674ee7a0749978cf8265ab91a60aea0f7d"
+            )),
+            Identifier::parse("swh:1:xyz:309cf2674ee7a0749978cf8265ab91a60aea0f7d"),
+            "Unknown object type."
+        );
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod swhid_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://archive.softwareheritage.org/swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d"
+            )),
+            Identifier::parse("swh:1:rev:309cf2674ee7a0749978cf8265ab91a60aea0f7d").to_uri()
+        );
+    }
+}
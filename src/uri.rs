@@ -2,15 +2,196 @@
 //!
 //! This is a fall-through case, as most types of identifiers are themselves URIs.
 //! This parser is greedy and doesn't attempt to avoid recognising other URI types (e.g. DOI). It relies on being called after the other types.
+//! URIs are only recognised with the `std` feature.
+//!
+//! Only schemes that are useful as identifiers in scholarly metadata are recognised. Others, such as "mailto:" and
+//! "tel:", fall through to the String type. Strings without a scheme are still recognised.
+//!
+//! Absolute IRIs (URIs containing non-ASCII characters) are converted to URIs per RFC 3987: the host is converted to
+//! its IDNA (Punycode) form, and non-ASCII characters elsewhere are percent-encoded as UTF-8.
 
 use crate::identifiers::{Identifier, IdentifierParseInput};
+#[cfg(feature = "std")]
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+#[cfg(feature = "std")]
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use http::Uri;
+#[cfg(feature = "std")]
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// Schemes that are recognised as URIs.
+#[cfg(feature = "std")]
+const ALLOWED_SCHEMES: &[&str] = &[
+    "http", "https", "ftp", "urn", "info", "doi", "hdl", "ark", "swh",
+];
+
+/// Characters that are encoded in a best-effort conversion, as well as non-ASCII characters. These are common in
+/// links copied from text, but aren't valid in a URI.
+#[cfg(feature = "std")]
+const BEST_EFFORT_ENCODE: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>');
 
+#[cfg(feature = "std")]
 pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
-    // Rely on the pre-compused URI.
-    input
-        .uri
-        .as_ref()
-        .map(|uri| Identifier::Uri(uri.to_string()))
+    let allowed = match scheme(&input.raw) {
+        Some(scheme) => allowed_scheme(scheme),
+        None => true,
+    };
+
+    if allowed {
+        // Rely on the pre-computed URI.
+        input.uri().map(|uri| Identifier::Uri(normalise(uri)))
+    } else {
+        None
+    }
+}
+
+/// Represent a URI as a string, with the host in lower case and without the default port for http or https, so that
+/// URLs differing only in those ways are equal. The path and query are case-sensitive, so they're kept as they are.
+#[cfg(feature = "std")]
+fn normalise(uri: &Uri) -> String {
+    let (Some(scheme), Some(authority)) = (uri.scheme_str(), uri.authority()) else {
+        return uri.to_string();
+    };
+
+    let default_port = match scheme {
+        "http" => Some(80),
+        "https" => Some(443),
+        _ => None,
+    };
+
+    let mut result = format!("{}://", scheme);
+
+    if let Some((userinfo, _)) = authority.as_str().rsplit_once('@') {
+        result.push_str(userinfo);
+        result.push('@');
+    }
+
+    result.push_str(&authority.host().to_lowercase());
+
+    if let Some(port) = authority
+        .port_u16()
+        .filter(|port| Some(*port) != default_port)
+    {
+        result.push_str(&format!(":{}", port));
+    }
+
+    result.push_str(uri.path_and_query().map_or("/", |path| path.as_str()));
+
+    result
+}
+
+#[cfg(feature = "std")]
+fn allowed_scheme(scheme: &str) -> bool {
+    ALLOWED_SCHEMES
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+}
+
+/// Find the scheme at the start of the input, if there is one.
+/// The URI parser reads inputs like "mailto:a@example.com" as an authority, so this works on the raw input.
+/// RFC 3986 allows ".", "+" and "-" in a scheme, but "." is rejected here. No allowed scheme contains one, and text before
+/// a colon with a dot in it is usually a host, so "example.com:8080" is a host and port, not a scheme. Without a dot,
+/// "localhost:8080" can't be told apart from a scheme, so "localhost" is read as one, and isn't allowed.
+#[cfg(feature = "std")]
+fn scheme(input: &str) -> Option<&str> {
+    let (scheme, _) = input.split_once(':')?;
+
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-'));
+
+    if valid {
+        Some(scheme)
+    } else {
+        None
+    }
+}
+
+/// Convert an absolute IRI, e.g. "http://例え.jp/®", into a URI string.
+/// Returns None if the input is already ASCII, or isn't an absolute IRI with a "scheme://" prefix.
+/// Relative strings containing Unicode are left alone, as they're more likely to be text than links.
+#[cfg(feature = "std")]
+pub(crate) fn iri_to_uri(input: &str) -> Option<String> {
+    // ASCII controls aren't valid in an IRI, so don't try to rescue them by encoding.
+    if input.is_ascii() || input.chars().any(|c| c.is_ascii_control()) {
+        return None;
+    }
+
+    encode_iri(input, CONTROLS)
+}
+
+/// Convert a string that wasn't recognised as a URI into one, on a best-effort basis, e.g. "http://例え.jp/a b".
+/// As well as IRI conversion, characters such as spaces are percent-encoded. Returns None unless the result is an
+/// absolute URI with an allowed scheme.
+#[cfg(feature = "std")]
+pub(crate) fn best_effort(input: &str) -> Option<String> {
+    if input.chars().any(|c| c.is_ascii_control()) {
+        return None;
+    }
+
+    let encoded = encode_iri(input, BEST_EFFORT_ENCODE)?;
+    let uri = Uri::from_str(&encoded).ok()?;
+
+    if uri.scheme_str().is_some_and(allowed_scheme) {
+        Some(normalise(&uri))
+    } else {
+        None
+    }
+}
+
+/// Without std there is no URI parser, so never convert.
+#[cfg(not(feature = "std"))]
+pub(crate) fn best_effort(_input: &str) -> Option<String> {
+    None
+}
+
+/// Convert an absolute IRI with a "scheme://" prefix into a URI string, encoding the given characters outside the
+/// host, as well as non-ASCII characters.
+#[cfg(feature = "std")]
+fn encode_iri(input: &str, encode: &'static AsciiSet) -> Option<String> {
+    let (scheme, rest) = input.split_once("://")?;
+
+    // The authority runs until the start of the path, query or fragment.
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+
+    // Only the host part of the authority is subject to IDNA.
+    let (userinfo, host_port) = match authority.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, authority),
+    };
+
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => (host, Some(port)),
+        _ => (host_port, None),
+    };
+
+    let ascii_host = idna::domain_to_ascii(host).ok()?;
+
+    let mut result = String::from(scheme);
+    result.push_str("://");
+    if let Some(userinfo) = userinfo {
+        result.extend(utf8_percent_encode(userinfo, encode));
+        result.push('@');
+    }
+    result.push_str(&ascii_host);
+    if let Some(port) = port {
+        result.push(':');
+        result.push_str(port);
+    }
+    result.extend(utf8_percent_encode(path, encode));
+
+    Some(result)
+}
+
+/// Without std there is no URI parser, so never recognise a URI.
+#[cfg(not(feature = "std"))]
+pub(crate) fn try_parse(_input: &IdentifierParseInput) -> Option<Identifier> {
+    None
 }
 
 /// Represent a URI Identifier type as a URI string.
@@ -27,7 +208,7 @@ pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
     to_uri(input)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod parse_tests {
     use super::*;
 
@@ -48,17 +229,123 @@ mod parse_tests {
         );
     }
 
-    /// Unencoded Unicode strings are not valid URIs, and are not parsed as such.
+    /// Schemes that aren't identifiers fall through to the String type.
     #[test]
-    fn parse_invalid() {
+    fn disallowed_schemes() {
+        let examples = [
+            "mailto:foo@example.com",
+            "tel:+44-1234-567890",
+            "javascript:alert(1)",
+            // Indistinguishable from a "localhost" scheme.
+            "localhost:8080",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::String(String::from(*example)),
+                Identifier::parse(example),
+                "Expected {} not to be a URI",
+                example
+            );
+        }
+    }
+
+    #[test]
+    fn allowed_schemes() {
         assert_eq!(
-            Identifier::String(String::from("http://example.com/®")),
+            Identifier::Uri(String::from("ftp://example.com/file")),
+            Identifier::parse("ftp://example.com/file")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("example.com:8080")),
+            Identifier::parse("example.com:8080"),
+            "Host and port isn't a scheme."
+        );
+    }
+
+    /// The host is case-insensitive and the default port is redundant, so both are normalised.
+    #[test]
+    fn normalised_host_and_port() {
+        let expected = Identifier::Uri(String::from("http://example.com/x"));
+
+        assert_eq!(expected, Identifier::parse("http://Example.com:80/x"));
+        assert_eq!(expected, Identifier::parse("HTTP://EXAMPLE.COM/x"));
+        assert_eq!(
+            Identifier::parse("http://Example.com:80/x"),
+            Identifier::parse("http://example.com/x")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com/Path?Q=1")),
+            Identifier::parse("https://EXAMPLE.com:443/Path?Q=1"),
+            "Path and query should keep their case."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("https://example.com:80/x")),
+            Identifier::parse("https://example.com:80/x"),
+            "Port is only dropped if it's the default for the scheme."
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from("http://User@example.com:8080/x")),
+            Identifier::parse("http://User@Example.com:8080/x"),
+            "User info should keep its case."
+        );
+    }
+
+    /// Unicode paths in absolute IRIs are percent-encoded.
+    #[test]
+    fn parse_iri_path() {
+        assert_eq!(
+            Identifier::Uri(String::from("http://example.com/%C2%AE")),
             Identifier::parse("http://example.com/®")
         );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "https://example.com/%E8%AB%96%E6%96%87?q=%C3%A9"
+            )),
+            Identifier::parse("https://example.com/論文?q=é"),
+            "Query should also be encoded."
+        );
+    }
+
+    /// Unicode hosts in absolute IRIs are converted to IDNA form.
+    #[test]
+    fn parse_iri_host() {
+        assert_eq!(
+            Identifier::Uri(String::from("https://xn--r8jz45g.jp/")),
+            Identifier::parse("https://例え.jp/")
+        );
+
+        assert_eq!(
+            Identifier::Uri(String::from(
+                "http://xn--bcher-kva.example:8080/b%C3%BCcher"
+            )),
+            Identifier::parse("http://Bücher.example:8080/bücher"),
+            "Port should be preserved and host normalised."
+        );
+    }
+
+    /// Unicode strings that aren't absolute IRIs are not parsed as URIs.
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Identifier::String(String::from("café®")),
+            Identifier::parse("café®")
+        );
+
+        assert_eq!(
+            Identifier::String(String::from("http://example.com/\u{1}®")),
+            Identifier::parse("http://example.com/\u{1}®"),
+            "Control characters aren't valid in an IRI."
+        );
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod end_to_end_tests {
     use super::*;
 
@@ -69,4 +356,51 @@ mod end_to_end_tests {
             Identifier::parse("http://example.com/").to_uri().unwrap()
         );
     }
+
+    #[test]
+    fn best_effort() {
+        let identifier = Identifier::parse("http://例え.jp/a b");
+
+        assert_eq!(
+            identifier,
+            Identifier::String(String::from("http://例え.jp/a b")),
+            "Spaces aren't valid in a URI, so this isn't recognised."
+        );
+        assert_eq!(identifier.to_uri(), None, "to_uri should be conservative.");
+        assert_eq!(
+            identifier.try_to_uri_best_effort(),
+            Some(String::from("http://xn--r8jz45g.jp/a%20b"))
+        );
+
+        assert_eq!(
+            Identifier::parse("https://de.wikipedia.org/wiki/Straße <1>").try_to_uri_best_effort(),
+            Some(String::from(
+                "https://de.wikipedia.org/wiki/Stra%C3%9Fe%20%3C1%3E"
+            ))
+        );
+
+        assert_eq!(
+            Identifier::parse("10.5555/12345678").try_to_uri_best_effort(),
+            Identifier::parse("10.5555/12345678").to_uri(),
+            "Other types are converted as to_uri would."
+        );
+    }
+
+    #[test]
+    fn best_effort_not_link() {
+        let examples = [
+            "hello world",
+            "mailto:a b@example.com",
+            "http://例え.jp/\u{1}",
+        ];
+
+        for example in examples.iter() {
+            assert_eq!(
+                Identifier::parse(example).try_to_uri_best_effort(),
+                None,
+                "Expected {} not to be converted to a URI",
+                example
+            );
+        }
+    }
 }
@@ -0,0 +1,170 @@
+//! URN:NBN, National Bibliography Number
+//! See RFC 8458 <https://www.rfc-editor.org/rfc/rfc8458>
+//!
+//! Used by national libraries to identify publications, particularly those deposited online. An NBN is a country code,
+//! optionally followed by colon-separated sub-namespaces for the assigning agency, then a hyphen and a string assigned
+//! by the agency, e.g. "urn:nbn:de:bsz:93-opus-59016" or "urn:nbn:fi-fe20031153".
+//!
+//! Per RFC 8458, the "urn:nbn:" prefix, country code and sub-namespaces are case-insensitive, so are lower-cased. The
+//! string after the hyphen is kept as it is. Some agencies append a check digit, but not all, so it isn't validated.
+
+use crate::identifiers::{Identifier, IdentifierParseInput};
+use alloc::format;
+use alloc::string::String;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Scheme and namespace, in lower case.
+const PREFIX: &str = "urn:nbn:";
+
+/// Hosts of NBN resolvers, which take the whole URN as the path.
+const RESOLVER_HOSTS: &[&str] = &["nbn-resolving.org", "nbn-resolving.de", "urn.fi"];
+
+lazy_static! {
+    /// Country code and sub-namespaces, in lower case.
+    static ref NAMESPACE_RE: Regex = Regex::new(r"^[a-z]{2}(?::[a-z0-9]+)*$").unwrap();
+}
+
+/// Parse an input string as a URN:NBN.
+///
+/// Accepts:
+///  - URN, in any case, e.g. "urn:nbn:de:bsz:93-opus-59016" or "URN:NBN:fi-fe20031153".
+///  - Resolver URL, e.g. "https://nbn-resolving.org/urn:nbn:de:bsz:93-opus-59016" or
+///    "http://urn.fi/URN:NBN:fi-fe20031153".
+pub(crate) fn try_parse(input: &IdentifierParseInput) -> Option<Identifier> {
+    from_urn(&input.raw).or_else(|| {
+        let host = input.host_lowercase()?;
+
+        if RESOLVER_HOSTS.contains(&host.as_str()) {
+            input.path_no_slash().and_then(|path| from_urn(&path))
+        } else {
+            None
+        }
+    })
+}
+
+/// Parse a bare URN:NBN, normalising the case of the prefix and namespace.
+fn from_urn(input: &str) -> Option<Identifier> {
+    let prefix = input.get(..PREFIX.len())?;
+
+    if !prefix.eq_ignore_ascii_case(PREFIX) {
+        return None;
+    }
+
+    let (namespace, nss) = input[PREFIX.len()..].split_once('-')?;
+    let namespace = namespace.to_lowercase();
+
+    if NAMESPACE_RE.is_match(&namespace)
+        && !nss.is_empty()
+        && !nss.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        Some(Identifier::UrnNbn(format!(
+            "{}{}-{}",
+            PREFIX, namespace, nss
+        )))
+    } else {
+        None
+    }
+}
+
+/// Encode a URN:NBN as a URI on the nbn-resolving.org resolver.
+/// Will always return a result if a URN:NBN type is supplied.
+pub(crate) fn to_uri(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::UrnNbn(value) => Some(format!("https://nbn-resolving.org/{}", value)),
+        _ => None,
+    }
+}
+
+/// Encode a URN:NBN as a stable string, which is the URN.
+/// Will always return a String if a URN:NBN type is supplied.
+pub(crate) fn to_stable_string(input: &Identifier) -> Option<String> {
+    match input {
+        Identifier::UrnNbn(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod urn_nbn_parser_tests {
+    use super::*;
+    use crate::identifiers::IdentifierType;
+
+    #[test]
+    fn german() {
+        let expected = Identifier::UrnNbn(String::from("urn:nbn:de:bsz:93-opus-59016"));
+
+        assert_eq!(expected, Identifier::parse("urn:nbn:de:bsz:93-opus-59016"));
+        assert_eq!(expected, Identifier::parse("URN:NBN:DE:BSZ:93-opus-59016"));
+        assert_eq!(
+            expected,
+            Identifier::parse("https://nbn-resolving.org/urn:nbn:de:bsz:93-opus-59016")
+        );
+    }
+
+    #[test]
+    fn finnish() {
+        let expected = Identifier::UrnNbn(String::from("urn:nbn:fi-fe20031153"));
+
+        assert_eq!(expected, Identifier::parse("URN:NBN:fi-fe20031153"));
+        assert_eq!(
+            expected,
+            Identifier::parse("http://urn.fi/URN:NBN:fi-fe20031153")
+        );
+
+        assert_eq!(
+            Identifier::UrnNbn(String::from("urn:nbn:fi:jyu-201203011347")),
+            Identifier::parse("urn:nbn:FI:jyu-201203011347")
+        );
+    }
+
+    #[test]
+    fn case_of_nss() {
+        assert_eq!(
+            Identifier::UrnNbn(String::from("urn:nbn:se:uu:diva-ABC123")),
+            Identifier::parse("URN:NBN:SE:UU:DIVA-ABC123"),
+            "Only the part before the hyphen should be lower-cased."
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let examples = [
+            // No hyphen.
+            "urn:nbn:de:bsz:93",
+            // Nothing after the hyphen.
+            "urn:nbn:de-",
+            // Country code must be two letters.
+            "urn:nbn:deu-12345",
+            "urn:nbn:1a-12345",
+            // Other URN namespaces.
+            "urn:isbn:9780306406157",
+            "urn:nbnx:de-12345",
+        ];
+
+        for example in examples.iter() {
+            assert_ne!(
+                Identifier::parse(example).identifier_type(),
+                IdentifierType::UrnNbn,
+                "Expected {} not to be a URN:NBN",
+                example
+            );
+        }
+    }
+}
+
+/// Tests for the end-to-end behaviour of the parser and then conversion back to URI.
+#[cfg(test)]
+mod urn_nbn_end_to_end_tests {
+    use super::*;
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            Some(String::from(
+                "https://nbn-resolving.org/urn:nbn:de:bsz:93-opus-59016"
+            )),
+            Identifier::parse("URN:NBN:de:bsz:93-opus-59016").to_uri()
+        );
+    }
+}